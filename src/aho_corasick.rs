@@ -0,0 +1,185 @@
+use std::collections::VecDeque;
+use std::ops::Range;
+
+// Aho–Corasick automaton finding all occurrences of several patterns in a single pass
+//
+// the failure links are resolved while building, so every state has a transition for every
+// byte and scanning costs one table lookup per byte of the text plus one step per match;
+// works on bytes, matches of UTF-8 patterns in UTF-8 text always lie on char boundaries
+
+const ALPHABET: usize = 256;
+
+const ROOT: usize = 0;
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct AhoCorasick {
+    // ALPHABET transitions per state
+    transitions: Vec<u32>,
+    // patterns ending in each state, including the ones ending in its failure states
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Match {
+    // index of the pattern in the list the automaton was built from
+    pub pattern: usize,
+    pub span: Range<usize>
+}
+
+impl AhoCorasick {
+    // empty patterns never match
+    pub fn new<P: AsRef<[u8]>>(patterns: &[P]) -> AhoCorasick {
+        let mut automaton = AhoCorasick {
+            transitions: vec![0; ALPHABET],
+            outputs: vec![vec![]],
+            lengths: patterns.iter().map(|pattern| pattern.as_ref().len()).collect()
+        };
+
+        // trie of the patterns, 0 is a missing transition as nothing leads back to the root
+        for (index, pattern) in patterns.iter().enumerate() {
+            let pattern = pattern.as_ref();
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut state = ROOT;
+            for &byte in pattern {
+                let next = automaton.transitions[state * ALPHABET + byte as usize] as usize;
+                state =
+                    if next == ROOT {
+                        automaton.add_state(state, byte)
+                    } else {
+                        next
+                    };
+            }
+
+            automaton.outputs[state].push(index);
+        }
+
+        // breadth first, the failure state of a state is shallower and complete already
+        let mut failures = vec![ROOT; automaton.outputs.len()];
+        let mut queue: VecDeque<usize> =
+            automaton.transitions[..ALPHABET].iter()
+                .map(|&next| next as usize)
+                .filter(|&next| next != ROOT)
+                .collect();
+
+        while let Some(state) = queue.pop_front() {
+            let failure = failures[state];
+            let inherited = automaton.outputs[failure].clone();
+            automaton.outputs[state].extend(inherited);
+
+            for byte in 0..ALPHABET {
+                let next = automaton.transitions[state * ALPHABET + byte] as usize;
+                let failure_next = automaton.transitions[failure * ALPHABET + byte];
+
+                if next == ROOT {
+                    automaton.transitions[state * ALPHABET + byte] = failure_next;
+                } else {
+                    failures[next] = failure_next as usize;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        automaton
+    }
+
+    fn add_state(&mut self, from: usize, byte: u8) -> usize {
+        let state = self.outputs.len();
+
+        self.transitions[from * ALPHABET + byte as usize] = state as u32;
+        self.transitions.extend([0; ALPHABET]);
+        self.outputs.push(vec![]);
+
+        state
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        self.lengths.len()
+    }
+
+    // all occurrences, overlapping ones included, ordered by their end,
+    // several ending at the same byte by decreasing length
+    pub fn find_overlapping<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        text.iter()
+            .scan(ROOT, |state, &byte| {
+                *state = self.transitions[*state * ALPHABET + byte as usize] as usize;
+                Some(*state)
+            })
+            .enumerate()
+            .flat_map(move |(index, state)| {
+                self.outputs[state].iter()
+                    .map(move |&pattern| Match { pattern, span: index + 1 - self.lengths[pattern]..index + 1 })
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod find_overlapping {
+        use crate::aho_corasick::{AhoCorasick, Match};
+        use crate::random::Rng;
+
+        fn find(patterns: &[&str], text: &str) -> Vec<(usize, usize, usize)> {
+            AhoCorasick::new(patterns)
+                .find_overlapping(text.as_bytes())
+                .map(|Match { pattern, span }| (pattern, span.start, span.end))
+                .collect()
+        }
+
+        #[test]
+        fn it_should_find_overlapping_occurrences() {
+            assert_eq!(
+                vec![(0, 0, 5), (1, 4, 7), (2, 5, 7), (1, 8, 11), (2, 9, 11)],
+                find(&["eight", "two", "wo", "1"], "eightwoxtwo")
+            );
+        }
+
+        #[test]
+        fn it_should_find_patterns_sharing_prefixes() {
+            assert_eq!(
+                vec![(0, 0, 4), (1, 0, 8), (2, 4, 8), (0, 9, 13)],
+                find(&["drei", "dreizehn", "zehn"], "dreizehn drei")
+            );
+        }
+
+        #[test]
+        fn it_should_report_each_index_of_repeated_patterns() {
+            assert_eq!(vec![(1, 0, 2), (2, 0, 2), (1, 1, 3), (2, 1, 3)], find(&["", "aa", "aa"], "aaa"));
+            assert_eq!(Vec::<(usize, usize, usize)>::new(), find(&[], "aaa"));
+        }
+
+        #[test]
+        fn it_should_find_what_a_naive_search_finds() {
+            let mut rng = Rng::new(2023);
+
+            for _ in 0..50 {
+                let word = |rng: &mut Rng| (0..rng.range(1..5)).map(|_| (b'a' + rng.range(0..3) as u8) as char).collect::<String>();
+                let patterns: Vec<String> = (0..rng.range(1..8)).map(|_| word(&mut rng)).collect();
+                let text: String = (0..rng.range(0..40)).map(|_| (b'a' + rng.range(0..4) as u8) as char).collect();
+
+                let mut expected: Vec<(usize, usize, usize)> =
+                    (0..text.len())
+                        .flat_map(|start| {
+                            patterns.iter().enumerate()
+                                .filter(|(_, pattern)| text[start..].starts_with(pattern.as_str()))
+                                .map(move |(index, pattern)| (index, start, start + pattern.len()))
+                                .collect::<Vec<_>>()
+                        })
+                        .collect();
+                expected.sort_by_key(|&(index, start, end)| (end, start, index));
+
+                let mut found = find(&patterns.iter().map(String::as_str).collect::<Vec<_>>(), &text);
+                found.sort_by_key(|&(index, start, end)| (end, start, index));
+
+                assert_eq!(expected, found, "{:?} in '{}'", patterns, text);
+            }
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::{fmt, fs, io};
+
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::runner::{self, Answers};
+
+// Accepted answers of a puzzle input, stored as
+//
+// part1: <answer>
+// part2: <answer>
+//
+// either part may be missing, empty lines and lines starting with '#' are ignored
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub struct KnownAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing
+}
+
+impl KnownAnswers {
+    pub fn parse(text: &str) -> Result<KnownAnswers, ParseError> {
+        let mut answers = KnownAnswers::default();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) =
+                line.split_once(':')
+                    .ok_or(ParseError::at_end(line, ParseErrorKind::MissingDelimiter(":")).on_line(index+1))?;
+
+            let answer =
+                match key.trim() {
+                    "part1" => &mut answers.part1,
+                    "part2" => &mut answers.part2,
+                    _ => return Err(ParseError::at(line, key, ParseErrorKind::Unexpected { expected: "part1 or part2", found: key.to_string() }).on_line(index+1))
+                };
+
+            *answer = Some(value.trim().to_string());
+        }
+
+        Ok(answers)
+    }
+
+    // reads an answers file, a missing file is a valid store without answers
+    pub fn load(path: &str) -> io::Result<KnownAnswers> {
+        match fs::read_to_string(path) {
+            Ok(text) =>
+                KnownAnswers::parse(&text)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, err))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(KnownAnswers::default()),
+            Err(err) => Err(io::Error::new(err.kind(), format!("{}: {}", path, err)))
+        }
+    }
+}
+
+impl fmt::Display for KnownAnswers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(part1) = &self.part1 {
+            writeln!(f, "part1: {}", part1)?;
+        }
+
+        if let Some(part2) = &self.part2 {
+            writeln!(f, "part2: {}", part2)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail => f.write_str("FAIL"),
+            Verdict::Missing => f.write_str("MISSING")
+        }
+    }
+}
+
+// a failing solver never passes, even without a known answer
+pub fn verdict(answer: &io::Result<String>, expected: Option<&str>) -> Verdict {
+    match (answer, expected) {
+        (Err(_), _) => Verdict::Fail,
+        (Ok(_), None) => Verdict::Missing,
+        (Ok(answer), Some(expected)) if answer == expected => Verdict::Pass,
+        (Ok(_), Some(_)) => Verdict::Fail
+    }
+}
+
+fn describe(answer: &io::Result<String>, expected: Option<&str>) -> String {
+    let verdict = verdict(answer, expected);
+
+    match (answer, expected) {
+        (Err(err), _) => format!("{} error: {}", verdict, err),
+        (Ok(answer), Some(expected)) if verdict == Verdict::Fail => format!("{} {} (expected {})", verdict, answer, expected),
+        (Ok(answer), _) => format!("{} {}", verdict, answer)
+    }
+}
+
+// table of the verdicts of both parts for each day, known answers are given in the same order as the answers
+pub fn format_report(answers: &[Answers], known_answers: &[KnownAnswers]) -> String {
+    let rows: Vec<[String; 3]> =
+        answers.iter().zip(known_answers.iter())
+            .map(|(answers, known_answers)| [
+                answers.day.to_string(),
+                describe(&answers.part1, known_answers.part1.as_deref()),
+                describe(&answers.part2, known_answers.part2.as_deref())
+            ])
+            .collect();
+
+    runner::format_columns(["Day", "Part 1", "Part 2"], &rows)
+}
+
+pub fn has_failures(answers: &[Answers], known_answers: &[KnownAnswers]) -> bool {
+    answers.iter().zip(known_answers.iter())
+        .any(|(answers, known_answers)| {
+            verdict(&answers.part1, known_answers.part1.as_deref()) == Verdict::Fail
+                || verdict(&answers.part2, known_answers.part2.as_deref()) == Verdict::Fail
+        })
+}
+
+#[cfg(test)]
+mod test {
+    mod parse {
+        use crate::answers::KnownAnswers;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_should_read_both_parts() {
+            let answers = KnownAnswers { part1: Some("56465".to_string()), part2: Some("55902".to_string()) };
+
+            assert_eq!(Ok(answers.clone()), KnownAnswers::parse("part1: 56465\npart2: 55902\n"));
+            assert_eq!(Ok(answers.clone()), KnownAnswers::parse("# day 1\n\npart2:55902\npart1:  56465"));
+            assert_eq!("part1: 56465\npart2: 55902\n", answers.to_string());
+        }
+
+        #[test]
+        fn it_should_allow_missing_parts() {
+            assert_eq!(
+                Ok(KnownAnswers { part1: Some("4".to_string()), part2: None }),
+                KnownAnswers::parse("part1: 4")
+            );
+        }
+
+        #[test]
+        fn it_should_report_invalid_lines() {
+            assert_eq!(
+                Err(ParseError::new(2, 1, ParseErrorKind::Unexpected { expected: "part1 or part2", found: "part3".to_string() })),
+                KnownAnswers::parse("part1: 4\npart3: 5")
+            );
+
+            assert_eq!(
+                Err(ParseError::new(1, 6, ParseErrorKind::MissingDelimiter(":"))),
+                KnownAnswers::parse("part1")
+            );
+        }
+
+        #[test]
+        fn it_should_treat_a_missing_file_as_empty_store() {
+            assert_eq!(KnownAnswers::default(), KnownAnswers::load("answers/day99.txt").unwrap());
+        }
+    }
+
+    mod verdict {
+        use std::io;
+
+        use crate::answers::{verdict, Verdict};
+
+        #[test]
+        fn it_should_compare_with_the_known_answer() {
+            assert_eq!(Verdict::Pass, verdict(&Ok("42".to_string()), Some("42")));
+            assert_eq!(Verdict::Fail, verdict(&Ok("41".to_string()), Some("42")));
+            assert_eq!(Verdict::Missing, verdict(&Ok("42".to_string()), None));
+            assert_eq!(Verdict::Fail, verdict(&Err(io::Error::other("no digits")), None));
+        }
+    }
+
+    mod format_report {
+        use std::io;
+
+        use crate::answers::{format_report, has_failures, KnownAnswers};
+        use crate::runner::Answers;
+
+        #[test]
+        fn it_should_report_a_verdict_per_part() {
+            let answers = [
+                Answers { day: 1, part1: Ok("42".to_string()), part2: Ok("7".to_string()) },
+                Answers { day: 2, part1: Err(io::Error::other("no digits")), part2: Ok("3".to_string()) }
+            ];
+            let known_answers = [
+                KnownAnswers { part1: Some("42".to_string()), part2: Some("8".to_string()) },
+                KnownAnswers::default()
+            ];
+
+            assert_eq!(
+"Day | Part 1                | Part 2
+----+-----------------------+--------------------
+  1 | PASS 42               | FAIL 7 (expected 8)
+  2 | FAIL error: no digits | MISSING 3
+",
+
+                format_report(&answers, &known_answers)
+            );
+
+            assert!(has_failures(&answers, &known_answers));
+            assert!(!has_failures(&answers[..1], &[KnownAnswers { part1: None, part2: Some("7".to_string()) }]));
+        }
+    }
+}
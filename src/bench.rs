@@ -0,0 +1,222 @@
+use std::hint::black_box;
+use std::io;
+use std::time::{Duration, Instant};
+
+use crate::runner::{self, Day};
+
+pub const DEFAULT_RUNS: usize = 10;
+
+pub const STEPS: [&str; 3] = ["parse", "part1", "part2"];
+
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration
+}
+
+// timings of parse, part 1 and part 2 of a single day
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Benchmark {
+    pub day: u8,
+    pub runs: usize,
+    pub steps: [Timings; 3]
+}
+
+impl Timings {
+    pub fn from_samples(samples: &[Duration]) -> Timings {
+        assert!(!samples.is_empty(), "at least one sample is required");
+
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median =
+            if samples.len().is_multiple_of(2) {
+                (samples[middle-1] + samples[middle]) / 2
+            } else {
+                samples[middle]
+            };
+
+        Timings {
+            min: samples[0],
+            median,
+            max: samples[samples.len()-1]
+        }
+    }
+}
+
+// parses the input and solves both parts the given number of times
+//
+// fails with the first error of the solution, timings of failing runs are meaningless
+pub fn run(day: &Day, lines: &[String], runs: usize) -> io::Result<Benchmark> {
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let solution = black_box(day.parse(lines)?);
+        samples[0].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(solution.part1()?);
+        samples[1].push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(solution.part2()?);
+        samples[2].push(start.elapsed());
+    }
+
+    Ok(Benchmark {
+        day: day.number,
+        runs: runs.max(1),
+        steps: samples.map(|samples| Timings::from_samples(&samples))
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+pub fn format_table(benchmarks: &[Benchmark]) -> String {
+    let rows: Vec<[String; 5]> =
+        benchmarks.iter()
+            .flat_map(|benchmark| {
+                STEPS.iter().zip(benchmark.steps.iter())
+                    .map(|(step, timings)| [
+                        benchmark.day.to_string(),
+                        step.to_string(),
+                        format_duration(timings.min),
+                        format_duration(timings.median),
+                        format_duration(timings.max)
+                    ])
+            })
+            .collect();
+
+    runner::format_columns(["Day", "Step", "Min", "Median", "Max"], &rows)
+}
+
+// one line per day and step, durations in nanoseconds
+pub fn to_csv(benchmarks: &[Benchmark]) -> String {
+    let mut csv = "day,step,runs,min_ns,median_ns,max_ns\n".to_string();
+
+    for benchmark in benchmarks {
+        for (step, timings) in STEPS.iter().zip(benchmark.steps.iter()) {
+            csv += &format!(
+                "{},{},{},{},{},{}\n",
+                benchmark.day, step, benchmark.runs, timings.min.as_nanos(), timings.median.as_nanos(), timings.max.as_nanos()
+            );
+        }
+    }
+
+    csv
+}
+
+#[cfg(test)]
+mod test {
+    mod timings {
+        use std::time::Duration;
+
+        use crate::bench::Timings;
+
+        #[test]
+        fn it_should_determine_min_median_and_max() {
+            let ms = Duration::from_millis;
+
+            assert_eq!(
+                Timings { min: ms(1), median: ms(3), max: ms(9) },
+                Timings::from_samples(&[ms(9), ms(1), ms(3)])
+            );
+
+            assert_eq!(
+                Timings { min: ms(1), median: ms(4), max: ms(9) },
+                Timings::from_samples(&[ms(9), ms(1), ms(3), ms(5)]),
+                "even number of samples"
+            );
+        }
+    }
+
+    mod run {
+        use crate::bench::run;
+        use crate::runner::find_day;
+
+        #[test]
+        fn it_should_time_every_step_of_each_run() {
+            let lines = vec!["0 3 6 9 12 15".to_string()];
+            let benchmark = run(find_day(9).unwrap(), &lines, 3).unwrap();
+
+            assert_eq!(9, benchmark.day);
+            assert_eq!(3, benchmark.runs);
+            assert!(benchmark.steps.iter().all(|timings| timings.min <= timings.median && timings.median <= timings.max));
+        }
+
+        #[test]
+        fn it_should_fail_for_invalid_input() {
+            assert!(run(find_day(9).unwrap(), &["0 x".to_string()], 3).is_err());
+        }
+    }
+
+    mod format {
+        use std::time::Duration;
+
+        use crate::bench::{format_duration, format_table, to_csv, Benchmark, Timings};
+
+        fn benchmark() -> Benchmark {
+            let timings = |micros| Timings {
+                min: Duration::from_micros(micros),
+                median: Duration::from_micros(micros * 2),
+                max: Duration::from_micros(micros * 3)
+            };
+
+            Benchmark { day: 6, runs: 5, steps: [timings(1), timings(20), timings(3000)] }
+        }
+
+        #[test]
+        fn it_should_format_durations_with_a_suitable_unit() {
+            assert_eq!("999 ns", format_duration(Duration::from_nanos(999)));
+            assert_eq!("1.5 µs", format_duration(Duration::from_nanos(1_500)));
+            assert_eq!("12.3 ms", format_duration(Duration::from_micros(12_345)));
+            assert_eq!("2.00 s", format_duration(Duration::from_secs(2)));
+        }
+
+        #[test]
+        fn it_should_print_a_row_per_step() {
+            assert_eq!(
+"Day | Step  | Min     | Median  | Max
+----+-------+---------+---------+--------
+  6 | parse | 1.0 µs  | 2.0 µs  | 3.0 µs
+  6 | part1 | 20.0 µs | 40.0 µs | 60.0 µs
+  6 | part2 | 3.0 ms  | 6.0 ms  | 9.0 ms
+",
+
+                format_table(&[benchmark()])
+            );
+        }
+
+        #[test]
+        fn it_should_write_nanoseconds_to_csv() {
+            assert_eq!(
+"day,step,runs,min_ns,median_ns,max_ns
+6,parse,5,1000,2000,3000
+6,part1,5,20000,40000,60000
+6,part2,5,3000000,6000000,9000000
+",
+
+                to_csv(&[benchmark()])
+            );
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::{env, fs, process};
+
+use adventofcode_2023::answers::{self, KnownAnswers};
+use adventofcode_2023::bench::{self, Benchmark};
+use adventofcode_2023::cache::{self, Cache};
+use adventofcode_2023::runner::{self, Answers, BenchOptions, Day, Format, Options, Run};
+use adventofcode_2023::input_helper::Input;
+use adventofcode_2023::render;
+use adventofcode_2023::trace;
+
+const USAGE: &str = "usage: aoc [--input <path>|- | --text <text>] [-v|-vv] [--jobs <n> | --parallel] [--no-cache] [--clear-cache] [--format table|json | --check | --bench [--runs <n>] [--csv <path>]] <day>|<first>..<last>|all ...
+       aoc generate <day> [--seed <n>] [--size <n>]
+       aoc anonymize <day> [--input <path>|- | --text <text>] [--seed <n>] [<output>]
+       aoc render <day> [--input <path>|- | --text <text>] [--scale <n>] <output.ppm|.svg>";
+
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+
+    if args.peek().is_some_and(|arg| arg == "generate") {
+        args.next();
+        generate(args);
+        return;
+    }
+
+    if args.peek().is_some_and(|arg| arg == "anonymize") {
+        args.next();
+        anonymize(args);
+        return;
+    }
+
+    if args.peek().is_some_and(|arg| arg == "render") {
+        args.next();
+        render(args);
+        return;
+    }
+
+    let options = match runner::parse_options(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    trace::set_verbosity(options.verbosity);
+
+    if options.clear_cache {
+        match Cache::new(cache::DEFAULT_DIR).clear() {
+            Ok(removed) => eprintln!("removed {} cached answers", removed),
+            Err(err) => {
+                eprintln!("{}: {}", cache::DEFAULT_DIR, err);
+                process::exit(1);
+            }
+        }
+
+        if options.days.is_empty() {
+            return;
+        }
+    }
+
+    let days: Vec<&Day> =
+        options.days.iter()
+            .filter_map(|number| runner::find_day(*number))
+            .collect();
+
+    let succeeded =
+        match &options.bench {
+            Some(bench_options) => run_benchmarks(&days, &options, bench_options),
+            None if options.check => check_days(&days, options.jobs),
+            None => run_days(&days, &options)
+        };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+fn run_days(days: &[&Day], options: &Options) -> bool {
+    let inputs: Vec<(&Day, Input)> =
+        days.iter()
+            .map(|day| (*day, options.input.clone().unwrap_or(day.default_input())))
+            .collect();
+
+    let runs: Vec<Run> =
+        if options.cache {
+            runner::run_cached(&inputs, options.jobs, &Cache::new(cache::DEFAULT_DIR))
+        } else {
+            runner::run_parallel(&inputs, options.jobs)
+        };
+
+    for run in runs.iter() {
+        for warning in run.warnings.iter() {
+            eprintln!("warning: day {}, {}: {}", run.answers.day, run.input, warning);
+        }
+    }
+
+    match options.format {
+        Format::Table => {
+            let answers: Vec<Answers> = runs.into_iter().map(|run| run.answers).collect();
+            print!("{}", runner::format_table(&answers));
+
+            answers.iter().all(|answers| answers.part1.is_ok() && answers.part2.is_ok())
+        }
+        Format::Json => {
+            print!("{}", runner::format_json(&runs));
+
+            runs.iter().all(|run| run.answers.part1.is_ok() && run.answers.part2.is_ok())
+        }
+    }
+}
+
+fn check_days(days: &[&Day], jobs: usize) -> bool {
+    let mut succeeded = true;
+
+    let known_answers: Vec<KnownAnswers> =
+        days.iter()
+            .map(|day|
+                KnownAnswers::load(&day.answers_path())
+                    .unwrap_or_else(|err| {
+                        eprintln!("{}", err);
+                        succeeded = false;
+                        KnownAnswers::default()
+                    }))
+            .collect();
+
+    let inputs: Vec<(&Day, Input)> = days.iter().map(|day| (*day, day.default_input())).collect();
+    let answers: Vec<Answers> = runner::run_parallel(&inputs, jobs).into_iter().map(|run| run.answers).collect();
+
+    print!("{}", answers::format_report(&answers, &known_answers));
+
+    succeeded && !answers::has_failures(&answers, &known_answers)
+}
+
+fn run_benchmarks(days: &[&Day], options: &Options, bench_options: &BenchOptions) -> bool {
+    let mut succeeded = true;
+    let mut benchmarks: Vec<Benchmark> = vec![];
+
+    for day in days {
+        let input = options.input.clone().unwrap_or(day.default_input());
+
+        match input.read_lines().and_then(|lines| bench::run(day, &lines, bench_options.runs)) {
+            Ok(benchmark) => benchmarks.push(benchmark),
+            Err(err) => {
+                eprintln!("day {}: {}", day.number, err);
+                succeeded = false;
+            }
+        }
+    }
+
+    print!("{}", bench::format_table(&benchmarks));
+
+    if let Some(path) = &bench_options.csv {
+        if let Err(err) = fs::write(path, bench::to_csv(&benchmarks)) {
+            eprintln!("{}: {}", path, err);
+            succeeded = false;
+        }
+    }
+
+    succeeded
+}
+
+fn generate(args: impl Iterator<Item = String>) {
+    let options = match runner::parse_generate_options(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let day = runner::find_day(options.day).expect("day is registered in DAYS");
+    let mut stdout = BufWriter::new(io::stdout().lock());
+
+    let written =
+        day.generate(options.seed, options.size)
+            .iter()
+            .try_for_each(|line| writeln!(stdout, "{}", line))
+            .and_then(|_| stdout.flush());
+
+    if let Err(err) = written {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+// writes the anonymized input to the output, or stdout, and its answers next to it, or to stderr
+fn anonymize(args: impl Iterator<Item = String>) {
+    let options = match runner::parse_anonymize_options(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let day = runner::find_day(options.day).expect("day is registered in DAYS");
+    let input = options.input.unwrap_or(day.default_input());
+
+    let lines = match input.read_lines().and_then(|lines| day.anonymize(options.seed, &lines)) {
+        Ok(lines) => lines,
+        Err(err) => {
+            eprintln!("{}: {}", input, err);
+            process::exit(1);
+        }
+    };
+
+    // an input without answers is no fixture
+    let answers = day.solve(&lines);
+    let known_answers = match (answers.part1, answers.part2) {
+        (Ok(part1), Ok(part2)) => KnownAnswers { part1: Some(part1), part2: Some(part2) },
+        (Err(err), _) | (_, Err(err)) => {
+            eprintln!("the anonymized input of {} fails: {}", input, err);
+            process::exit(1);
+        }
+    };
+
+    let text: String = lines.iter().map(|line| format!("{}\n", line)).collect();
+
+    let Some(output) = options.output else {
+        print!("{}", text);
+        eprint!("{}", known_answers);
+        return;
+    };
+
+    let answers_path = Path::new(&output).with_extension("answers");
+    let written = fs::write(&output, text).and_then(|_| fs::write(&answers_path, known_answers.to_string()));
+
+    if let Err(err) = written {
+        eprintln!("{}: {}", output, err);
+        process::exit(1);
+    }
+}
+
+fn render(args: impl Iterator<Item = String>) {
+    let options = match runner::parse_render_options(args) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let day = runner::find_day(options.day).expect("day is registered in DAYS");
+    let input = options.input.unwrap_or(day.default_input());
+
+    let written =
+        input.read_lines()
+            .and_then(|lines| day.render(&lines))
+            .and_then(|tiles| fs::write(&options.output, render::render(&tiles, options.scale, &options.format)));
+
+    if let Err(err) = written {
+        eprintln!("{}: {}", options.output, err);
+        process::exit(1);
+    }
+}
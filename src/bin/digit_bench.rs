@@ -0,0 +1,130 @@
+use std::hint::black_box;
+use std::process;
+use std::time::{Duration, Instant};
+
+use adventofcode_2023::bench::{self, Timings};
+use adventofcode_2023::day01::{self, DigitVocabulary};
+use adventofcode_2023::random::Rng;
+use adventofcode_2023::runner;
+
+// Compares the day 1 digit scan of the automaton with checking every word at every character
+
+const USAGE: &str = "usage: digit_bench [--size <megabytes>] [--runs <n>]";
+
+struct Options {
+    size: usize,
+    runs: usize
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options { size: 8, runs: 5 };
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| -> Result<usize, String> {
+            let value = args.next().ok_or(format!("missing value for {}", arg))?;
+            value.parse().ok().filter(|value| *value > 0).ok_or(format!("invalid {}: '{}'", name, value))
+        };
+
+        match arg.as_str() {
+            "--size" => options.size = value("size")?,
+            "--runs" => options.runs = value("number of runs")?,
+            _ => return Err(format!("unknown option: {}", arg))
+        }
+    }
+
+    Ok(options)
+}
+
+// generated calibration lines of about the given number of bytes
+fn generate_input(megabytes: usize) -> Vec<String> {
+    let mut rng = Rng::new(2023);
+    let mut lines = vec![];
+    let mut size = 0;
+
+    while size < megabytes * 1024 * 1024 {
+        for line in day01::generate(&mut rng, 1000) {
+            size += line.len() + 1;
+            lines.push(line);
+        }
+    }
+
+    lines
+}
+
+// the scan before the automaton: every word of the vocabulary is tried at every character
+fn sum_naive(lines: &[String], vocabulary: &DigitVocabulary) -> u64 {
+    lines.iter()
+        .map(|line| {
+            let mut first_digit = None;
+            let mut last_digit = 0;
+
+            for (index, char) in line.char_indices() {
+                let digit = char.to_digit(10).map(|digit| digit as u8).or_else(|| vocabulary.match_at(&line[index..]).map(|(_, digit)| digit));
+
+                if let Some(digit) = digit {
+                    first_digit.get_or_insert(digit);
+                    last_digit = digit;
+                }
+            }
+
+            first_digit.map_or(0, |first_digit| (first_digit * 10 + last_digit) as u64)
+        })
+        .sum()
+}
+
+fn sum_automaton(lines: &[String], vocabulary: &DigitVocabulary) -> u64 {
+    lines.iter()
+        .map(|line| day01::process_line_with_vocabulary(line, vocabulary).unwrap_or_default() as u64)
+        .sum()
+}
+
+fn time(runs: usize, mut f: impl FnMut() -> u64) -> (u64, Timings) {
+    let mut result = 0;
+    let samples: Vec<Duration> =
+        (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                result = black_box(f());
+                start.elapsed()
+            })
+            .collect();
+
+    (result, Timings::from_samples(&samples))
+}
+
+fn main() {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let lines = generate_input(options.size);
+    eprintln!("{} lines, {} runs each", lines.len(), options.runs);
+
+    let vocabularies = [
+        ("english", DigitVocabulary::english()),
+        ("all built-in", DigitVocabulary::combine(&DigitVocabulary::BUILTIN.map(|name| DigitVocabulary::builtin(name).unwrap())).unwrap())
+    ];
+
+    let rows: Vec<[String; 5]> =
+        vocabularies.iter()
+            .map(|(name, vocabulary)| {
+                let (naive_sum, naive) = time(options.runs, || sum_naive(&lines, vocabulary));
+                let (automaton_sum, automaton) = time(options.runs, || sum_automaton(&lines, vocabulary));
+                assert_eq!(naive_sum, automaton_sum, "both scans must find the same digits");
+
+                [
+                    name.to_string(),
+                    vocabulary.words().count().to_string(),
+                    bench::format_duration(naive.median),
+                    bench::format_duration(automaton.median),
+                    format!("{:.1}x", naive.median.as_secs_f64() / automaton.median.as_secs_f64())
+                ]
+            })
+            .collect();
+
+    println!("{}", runner::format_columns(["Vocabulary", "Words", "Naive", "Automaton", "Speedup"], &rows));
+}
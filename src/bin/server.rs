@@ -0,0 +1,48 @@
+use std::net::TcpListener;
+use std::process;
+
+use adventofcode_2023::http;
+
+const USAGE: &str = "usage: server [--port <n>]";
+
+fn parse_port(mut args: impl Iterator<Item = String>) -> Result<u16, String> {
+    let mut port = http::DEFAULT_PORT;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--port" | "-p" => {
+                let value = args.next().ok_or(format!("missing value for {}", arg))?;
+                port = value.parse().map_err(|_| format!("invalid port: '{}'", value))?;
+            }
+            _ => return Err(format!("unknown option: {}", arg))
+        }
+    }
+
+    Ok(port)
+}
+
+fn main() {
+    let port = match parse_port(std::env::args().skip(1)) {
+        Ok(port) => port,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    // only reachable from this machine
+    let listener = match TcpListener::bind(("127.0.0.1", port)) {
+        Ok(listener) => listener,
+        Err(err) => {
+            eprintln!("cannot listen on port {}: {}", port, err);
+            process::exit(1);
+        }
+    };
+
+    eprintln!("listening on http://{}", listener.local_addr().map_or(format!("127.0.0.1:{}", port), |addr| addr.to_string()));
+
+    if let Err(err) = http::serve(listener) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,39 @@
+use std::io::{self, IsTerminal};
+use std::process;
+
+use adventofcode_2023::runner;
+use adventofcode_2023::shell;
+
+const USAGE: &str = "usage: shell <day> [--input <path> | --text <text>]";
+
+fn main() {
+    let options = match runner::parse_shell_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let day = runner::find_day(options.day).expect("day is registered in DAYS");
+    let input = options.input.unwrap_or(day.default_input());
+
+    let day_shell = match input.read_lines().and_then(|lines| day.shell(&lines)) {
+        Ok(day_shell) => day_shell,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+
+    // no prompt when the commands are piped in
+    let prompt = if io::stdin().is_terminal() { format!("day{:02}> ", day.number) } else { String::new() };
+    if io::stdin().is_terminal() {
+        println!("{} loaded, type help for the commands", input);
+    }
+
+    if let Err(err) = shell::repl(day_shell.as_ref(), io::stdin().lock(), io::stdout().lock(), &prompt) {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+// Answers stored on disk, one file per (day, part, input hash, solver version)
+//
+// a changed input or a new solver version gives a new key, stale entries are only
+// removed by clear()
+
+pub const DEFAULT_DIR: &str = ".cache";
+
+const EXTENSION: &str = "answer";
+
+pub struct Cache {
+    dir: PathBuf
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Key<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: u64,
+    pub version: &'a str
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Cache {
+        Cache { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, key: &Key) -> PathBuf {
+        self.dir.join(format!("day{:02}-part{}-{:016x}-{}.{}", key.day, key.part, key.input_hash, key.version, EXTENSION))
+    }
+
+    // a missing or unreadable entry is a miss
+    pub fn load(&self, key: &Key) -> Option<String> {
+        fs::read_to_string(self.path(key)).ok()
+    }
+
+    // written to a temporary file first, a concurrent load never sees half an answer
+    pub fn store(&self, key: &Key, answer: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let path = self.path(key);
+        let temporary = path.with_extension(format!("{}.tmp", process::id()));
+        fs::write(&temporary, answer)?;
+        fs::rename(&temporary, &path)
+    }
+
+    // removes every entry and returns how many there were, other files are left alone
+    pub fn clear(&self) -> io::Result<usize> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => return Err(err)
+        };
+
+        let mut removed = 0;
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == EXTENSION) {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+    use std::{env, fs};
+
+    // empty directory of its own for each test
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-cache-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+
+        dir
+    }
+
+    mod load {
+        use std::fs;
+
+        use crate::cache::test::test_dir;
+        use crate::cache::{Cache, Key};
+
+        #[test]
+        fn it_should_load_what_was_stored_under_the_same_key() {
+            let dir = test_dir("load");
+            let cache = Cache::new(&dir);
+            let key = Key { day: 8, part: 2, input_hash: 0xcbf29ce484222325, version: "0.1.0+1" };
+
+            assert_eq!(None, cache.load(&key));
+
+            cache.store(&key, "6").unwrap();
+
+            assert_eq!(Some("6".to_string()), cache.load(&key));
+            assert_eq!(None, cache.load(&Key { part: 1, ..key }));
+            assert_eq!(None, cache.load(&Key { input_hash: 1, ..key }));
+            assert_eq!(None, cache.load(&Key { version: "0.1.0+2", ..key }));
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+
+    mod clear {
+        use std::fs;
+
+        use crate::cache::test::test_dir;
+        use crate::cache::{Cache, Key};
+
+        #[test]
+        fn it_should_remove_the_entries_only() {
+            let dir = test_dir("clear");
+            let cache = Cache::new(&dir);
+
+            assert_eq!(0, cache.clear().unwrap());
+
+            for part in [1, 2] {
+                cache.store(&Key { day: 5, part, input_hash: 7, version: "1" }, "35").unwrap();
+            }
+            fs::write(dir.join("notes.txt"), "keep").unwrap();
+
+            assert_eq!(2, cache.clear().unwrap());
+            assert_eq!(None, cache.load(&Key { day: 5, part: 1, input_hash: 7, version: "1" }));
+            assert!(dir.join("notes.txt").exists());
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+    }
+}
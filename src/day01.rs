@@ -0,0 +1,1182 @@
+use std::{fs, io};
+use std::cmp::Reverse;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use crate::Solution;
+use crate::aho_corasick::AhoCorasick;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::shell::{self, Command, Commands};
+use crate::trace;
+
+pub struct Day01 {
+    pub lines: Vec<String>,
+    pub vocabulary: DigitVocabulary
+}
+
+impl Solution for Day01 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        Ok(Day01::with_vocabulary(lines, DigitVocabulary::english()))
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        sum_calibration_values(&self.lines, process_line_part1)
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        sum_calibration_values(&self.lines, |line| process_line_with_vocabulary(line, &self.vocabulary))
+    }
+}
+
+impl Commands for Day01 {
+    const COMMANDS: &'static [Command<Day01>] = &[
+        Command { name: "tokens", arguments: "<line>", help: "digits and words of the line as read by both parts", run: tokens_command },
+        Command { name: "disagreements", arguments: "", help: "lines whose calibration values differ between the parts", run: disagreements_command }
+    ];
+}
+
+// "tokens 3" prints the tokens of the third line with their spans and which of them make the value
+fn tokens_command(day: &Day01, arguments: &[&str]) -> Result<String, String> {
+    let [argument] = shell::arguments(arguments)?;
+    let number: usize = shell::parse_argument(argument, "line")?;
+
+    let line =
+        number.checked_sub(1)
+            .and_then(|index| day.lines.get(index))
+            .ok_or(format!("no line {}, there are {}", number, day.lines.len()))?;
+
+    Ok(format!(
+        "'{}'\npart 1: {}\npart 2: {}",
+        line,
+        report_part1(line).describe(line),
+        report_with_vocabulary(line, &day.vocabulary).describe(line)))
+}
+
+fn disagreements_command(day: &Day01, arguments: &[&str]) -> Result<String, String> {
+    let [] = shell::arguments(arguments)?;
+
+    let audits = audit(&day.lines, &day.vocabulary);
+    let disagreements: Vec<String> =
+        audits.iter()
+            .filter(|audit| !audit.agrees())
+            .map(|audit| {
+                let value = |report: &TokenReport| report.value().map_or_else(|err| err.to_string(), |value| value.to_string());
+                format!("line {}: part 1 {}, part 2 {}", audit.line, value(&audit.part1), value(&audit.part2))
+            })
+            .collect();
+
+    Ok(format!("{}{} of {} lines differ", disagreements.iter().map(|line| format!("{}\n", line)).collect::<String>(), disagreements.len(), audits.len()))
+}
+
+impl Day01 {
+    // part 2 reads the digit words of the given vocabulary instead of the English ones
+    pub fn with_vocabulary(lines: &[String], vocabulary: DigitVocabulary) -> Day01 {
+        Day01 { lines: lines.to_vec(), vocabulary }
+    }
+}
+
+// words standing for digits, e.g. "one" for 1
+//
+// several words may stand for the same digit, so one vocabulary can cover several languages,
+// but a word stands for a single digit; the lines are read in radix 10 unless another one
+// is chosen, words of digits beyond the radix are ignored
+//
+// numbers are words of several decimal digits, e.g. "twentythree", read in radix 10 only;
+// a token inside the span of a number, as "three" in "twentythree", is part of the number
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct DigitVocabulary {
+    words: Vec<(String, u8)>,
+    numbers: Vec<(String, u8)>,
+    radix: u32,
+    // kind and value of each pattern of the matcher
+    patterns: Vec<(TokenKind, u8)>,
+    matcher: AhoCorasick
+}
+
+// digits beyond 9 are letters, as in hexadecimal
+pub const MAX_RADIX: u32 = 36;
+
+// the words of the digits 1 to 9
+const ENGLISH: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+const GERMAN: [&str; 9] = ["eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun"];
+const FRENCH: [&str; 9] = ["un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf"];
+const SPANISH: [&str; 9] = ["uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve"];
+
+// the numbers from 10 to 19 and the tens from 20 to 90
+const TEENS: [&str; 10] = ["ten", "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen"];
+const TENS: [&str; 8] = ["twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+
+impl DigitVocabulary {
+    pub const BUILTIN: [&'static str; 4] = ["english", "german", "french", "spanish"];
+
+    fn new(words: Vec<(String, u8)>, numbers: Vec<(String, u8)>, radix: u32) -> DigitVocabulary {
+        // literal digits in upper and lower case, then the words
+        let mut texts: Vec<String> = vec![];
+        let mut patterns = vec![];
+
+        for digit in ('0'..='9').chain('a'..='z').chain('A'..='Z') {
+            if let Some(value) = digit.to_digit(radix) {
+                texts.push(digit.to_string());
+                patterns.push((TokenKind::Digit, value as u8));
+            }
+        }
+
+        for (word, value) in words.iter().filter(|(_, value)| (*value as u32) < radix) {
+            texts.push(word.clone());
+            patterns.push((TokenKind::Word, *value));
+        }
+
+        if radix == 10 {
+            for (number, value) in &numbers {
+                texts.push(number.clone());
+                patterns.push((TokenKind::Number, *value));
+            }
+        }
+
+        let matcher = AhoCorasick::new(&texts);
+
+        DigitVocabulary { words, numbers, radix, patterns, matcher }
+    }
+
+    fn from_table(table: &[&str; 9]) -> DigitVocabulary {
+        DigitVocabulary::new(table.iter().zip(1..).map(|(word, digit)| (word.to_string(), digit)).collect(), vec![], 10)
+    }
+
+    // the words of the puzzle
+    pub fn english() -> DigitVocabulary {
+        DigitVocabulary::from_table(&ENGLISH)
+    }
+
+    pub fn german() -> DigitVocabulary {
+        DigitVocabulary::from_table(&GERMAN)
+    }
+
+    pub fn french() -> DigitVocabulary {
+        DigitVocabulary::from_table(&FRENCH)
+    }
+
+    pub fn spanish() -> DigitVocabulary {
+        DigitVocabulary::from_table(&SPANISH)
+    }
+
+    // the English digits and the compound numbers from "ten" to "ninetynine" and "onehundred",
+    // each of them a single token
+    pub fn english_numbers() -> DigitVocabulary {
+        let english = DigitVocabulary::english();
+
+        let mut numbers: Vec<(String, u8)> = TEENS.iter().zip(10..).map(|(number, value)| (number.to_string(), value)).collect();
+        for (tens, value) in TENS.iter().zip((20..).step_by(10)) {
+            numbers.push((tens.to_string(), value));
+            numbers.extend(english.words().map(|(word, digit)| (format!("{}{}", tens, word), value + digit)));
+        }
+        numbers.push(("onehundred".to_string(), 100));
+
+        DigitVocabulary::new(english.words, numbers, 10)
+    }
+
+    // one of the built-in vocabularies by its name in BUILTIN
+    pub fn builtin(name: &str) -> Option<DigitVocabulary> {
+        match name {
+            "english" => Some(DigitVocabulary::english()),
+            "german" => Some(DigitVocabulary::german()),
+            "french" => Some(DigitVocabulary::french()),
+            "spanish" => Some(DigitVocabulary::spanish()),
+            _ => None
+        }
+    }
+
+    // "<digit>: <word> <word> ..." on each line, empty lines and lines starting with '#' are ignored
+    //
+    // digits range from 0 to 35 for radixes up to MAX_RADIX, words must not contain decimal digits
+    pub fn parse(text: &str) -> Result<DigitVocabulary, ParseError> {
+        let mut words = vec![];
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (digit, line_words) =
+                line.split_once(':')
+                    .ok_or(ParseError::at_end(line, ParseErrorKind::MissingDelimiter(":")).on_line(index+1))?;
+
+            let digit_token = digit.trim();
+            let digit =
+                digit_token.parse::<u8>().ok()
+                    .filter(|digit| (*digit as u32) < MAX_RADIX)
+                    .ok_or(ParseError::at(line, digit_token, ParseErrorKind::Unexpected { expected: "a digit from 0 to 35", found: digit_token.to_string() }).on_line(index+1))?;
+
+            let mut line_words = line_words.split_whitespace().peekable();
+            if line_words.peek().is_none() {
+                return Err(ParseError::at_end(line, ParseErrorKind::Missing("word")).on_line(index+1));
+            }
+
+            for word in line_words {
+                add_word(&mut words, word, digit)
+                    .map_err(|expected| ParseError::at(line, word, ParseErrorKind::Unexpected { expected, found: word.to_string() }).on_line(index+1))?;
+            }
+        }
+
+        Ok(DigitVocabulary::new(words, vec![], 10))
+    }
+
+    pub fn load(path: &str) -> io::Result<DigitVocabulary> {
+        let text = fs::read_to_string(path).map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path, err)))?;
+
+        DigitVocabulary::parse(&text)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, err)))
+    }
+
+    // the words of all the vocabularies, e.g. of several languages, read in radix 10
+    pub fn combine(vocabularies: &[DigitVocabulary]) -> Result<DigitVocabulary, String> {
+        let mut words = vec![];
+        let mut numbers = vec![];
+
+        for (word, digit) in vocabularies.iter().flat_map(|vocabulary| vocabulary.words()) {
+            add_word(&mut words, word, digit).map_err(|expected| format!("'{}' is not {}", word, expected))?;
+        }
+        for (number, value) in vocabularies.iter().flat_map(|vocabulary| vocabulary.numbers.iter()) {
+            add_word(&mut numbers, number, *value).map_err(|expected| format!("'{}' is not {}", number, expected))?;
+        }
+
+        Ok(DigitVocabulary::new(words, numbers, 10))
+    }
+
+    // the same words, reading lines in the radix: its literal digits in either case and the words of digits below it
+    pub fn with_radix(&self, radix: u32) -> Result<DigitVocabulary, String> {
+        if !(2..=MAX_RADIX).contains(&radix) {
+            return Err(format!("radix {} is not from 2 to {}", radix, MAX_RADIX));
+        }
+
+        Ok(DigitVocabulary::new(self.words.clone(), self.numbers.clone(), radix))
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u8)> {
+        self.words.iter().map(|(word, digit)| (word.as_str(), *digit))
+    }
+
+    pub fn numbers(&self) -> impl Iterator<Item = (&str, u8)> {
+        self.numbers.iter().map(|(number, value)| (number.as_str(), *value))
+    }
+
+    fn reads_numbers(&self) -> bool {
+        self.radix == 10 && !self.numbers.is_empty()
+    }
+
+    // the longest word of a digit below the radix at the start of the text, if any
+    pub fn match_at<'a>(&'a self, text: &str) -> Option<(&'a str, u8)> {
+        self.words()
+            .filter(|(word, digit)| (*digit as u32) < self.radix && text.starts_with(word))
+            .max_by_key(|(word, _)| word.len())
+    }
+
+    // values of the first and the last of find_tokens, without collecting the others
+    pub fn first_and_last_digit(&self, line: &str) -> Option<(u8, u8)> {
+        // the last match may lie inside a number, letters read as digits inside a word
+        if self.reads_numbers() || self.radix > 10 {
+            let tokens = self.find_tokens(line);
+            return Some((tokens.first()?.value, tokens.last()?.value));
+        }
+
+        let mut first: Option<(usize, Reverse<usize>, usize)> = None;
+        let mut last: Option<(usize, usize, usize)> = None;
+
+        for found in self.matcher.find_overlapping(line.as_bytes()) {
+            let (start, end) = (found.span.start, found.span.end);
+
+            if first.is_none_or(|(first_start, first_end, _)| (start, Reverse(end)) < (first_start, first_end)) {
+                first = Some((start, Reverse(end), found.pattern));
+            }
+            if last.is_none_or(|(last_start, last_end, _)| (start, end) > (last_start, last_end)) {
+                last = Some((start, end, found.pattern));
+            }
+        }
+
+        Some((self.patterns[first?.2].1, self.patterns[last?.2].1))
+    }
+
+    // the digits and words in the line, ordered by their start;
+    // where several words start at the same byte only the longest one is kept
+    //
+    // a single pass over the line, however many words there are
+    pub fn find_tokens(&self, line: &str) -> Vec<Token> {
+        let mut matches: Vec<_> = self.matcher.find_overlapping(line.as_bytes()).collect();
+        matches.sort_by_key(|found| (found.span.start, Reverse(found.span.end)));
+        matches.dedup_by_key(|found| found.span.start);
+
+        if self.reads_numbers() {
+            let mut number_end = 0;
+            matches.retain(|found| {
+                let outside = found.span.end > number_end;
+                if self.patterns[found.pattern].0 == TokenKind::Number {
+                    number_end = number_end.max(found.span.end);
+                }
+                outside
+            });
+        }
+
+        // letters read as digits in radixes above 10 are part of the words around them
+        let mut word_end = 0;
+        matches.retain(|found| {
+            if self.patterns[found.pattern].0 == TokenKind::Digit {
+                found.span.end > word_end
+            } else {
+                word_end = word_end.max(found.span.end);
+                true
+            }
+        });
+
+        matches.into_iter()
+            .map(|found| {
+                let (kind, value) = self.patterns[found.pattern];
+                Token { span: found.span, kind, value }
+            })
+            .collect()
+    }
+}
+
+// fails with what the word was expected to be
+fn add_word(words: &mut Vec<(String, u8)>, word: &str, digit: u8) -> Result<(), &'static str> {
+    if word.is_empty() || word.chars().any(|c| c.is_ascii_digit()) {
+        return Err("a word without digits");
+    }
+
+    match words.iter().find(|(known_word, _)| known_word == word) {
+        Some((_, known_digit)) if *known_digit != digit => Err("a word of another digit"),
+        Some(_) => Ok(()),
+        None => {
+            words.push((word.to_string(), digit));
+            Ok(())
+        }
+    }
+}
+
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum TokenKind {
+    Digit,
+    Word,
+    Number
+}
+
+// a digit, a word standing for one or a number, the span is in bytes of the line
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Token {
+    pub span: Range<usize>,
+    pub kind: TokenKind,
+    pub value: u8
+}
+
+// all tokens of a line and the two making its calibration value
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct TokenReport {
+    pub tokens: Vec<Token>,
+    // indices in tokens, none for a line without tokens
+    pub first: Option<usize>,
+    pub last: Option<usize>,
+    // of the vocabulary the tokens were found with
+    pub radix: u32
+}
+
+impl TokenReport {
+    // the tokens are ordered by their start
+    fn new(tokens: Vec<Token>, radix: u32) -> TokenReport {
+        let first = (!tokens.is_empty()).then_some(0);
+        let last = tokens.len().checked_sub(1);
+
+        TokenReport { tokens, first, last, radix }
+    }
+
+    pub fn first(&self) -> Option<&Token> {
+        self.first.map(|index| &self.tokens[index])
+    }
+
+    pub fn last(&self) -> Option<&Token> {
+        self.last.map(|index| &self.tokens[index])
+    }
+
+    // same as the process_line functions, the digits of the first token followed by the ones of the last
+    pub fn value(&self) -> Result<u64, &'static str> {
+        match (self.first(), self.last()) {
+            (Some(first), Some(last)) => concatenate([first.value, last.value], self.radix).ok_or("calibration value out of range"),
+            _ => Err("no digits")
+        }
+    }
+
+    // "two@1..4 one@3..6 3@6..7 four@7..11, first two, last four -> 24" for the line "xtwone3four"
+    pub fn describe(&self, line: &str) -> String {
+        let (Some(first), Some(last), Ok(value)) = (self.first(), self.last(), self.value()) else {
+            return "no digits".to_string();
+        };
+
+        let tokens: Vec<String> =
+            self.tokens.iter()
+                .map(|token| format!("{}@{}..{}", &line[token.span.clone()], token.span.start, token.span.end))
+                .collect();
+
+        format!(
+            "{}, first {}, last {} -> {}",
+            tokens.join(" "), &line[first.span.clone()], &line[last.span.clone()], value)
+    }
+}
+
+// the line read by both parts, numbered from 1
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct LineAudit {
+    pub line: usize,
+    pub part1: TokenReport,
+    pub part2: TokenReport
+}
+
+impl LineAudit {
+    pub fn agrees(&self) -> bool {
+        self.part1.value() == self.part2.value()
+    }
+}
+
+// the literal digits only
+pub fn report_part1(line: &str) -> TokenReport {
+    TokenReport::new(
+        line.char_indices()
+            .filter_map(|(index, char)| {
+                char.to_digit(10).map(|value| Token { span: index..index + 1, kind: TokenKind::Digit, value: value as u8 })
+            })
+            .collect(),
+        10)
+}
+
+pub fn report_part2(line: &str) -> TokenReport {
+    report_with_vocabulary(line, english_vocabulary())
+}
+
+pub fn report_with_vocabulary(line: &str, vocabulary: &DigitVocabulary) -> TokenReport {
+    TokenReport::new(vocabulary.find_tokens(line), vocabulary.radix)
+}
+
+// part 2 with the words of the vocabulary
+pub fn audit(lines: &[String], vocabulary: &DigitVocabulary) -> Vec<LineAudit> {
+    lines.iter().enumerate()
+        .map(|(index, line)| LineAudit {
+            line: index + 1,
+            part1: report_part1(line),
+            part2: report_with_vocabulary(line, vocabulary)
+        })
+        .collect()
+}
+
+// the first `count` digits followed by the last `count` digits, read as a number in the radix;
+// numbers add all their digits, as 23 and 4 make 234
+//
+// the two groups share digits when a line has fewer than twice as many, as the only digit of
+// a line counts twice in the puzzle
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct CalibrationRule {
+    pub count: usize,
+    pub radix: u32
+}
+
+impl CalibrationRule {
+    // first and last digit in decimal
+    pub const PUZZLE: CalibrationRule = CalibrationRule { count: 1, radix: 10 };
+
+    // the tokens are ordered by their start and of digits below the radix
+    pub fn value(&self, tokens: &[Token]) -> Result<u64, &'static str> {
+        if self.count == 0 {
+            return Err("at least one digit is required");
+        }
+        if !(2..=MAX_RADIX).contains(&self.radix) {
+            return Err("radix out of range");
+        }
+        if tokens.is_empty() {
+            return Err("no digits");
+        }
+        if tokens.len() < self.count {
+            return Err("too few digits");
+        }
+
+        let tokens = tokens[..self.count].iter().chain(&tokens[tokens.len() - self.count..]);
+
+        concatenate(tokens.map(|token| token.value), self.radix).ok_or("calibration value out of range")
+    }
+}
+
+// reads lines with the vocabulary in the radix of the rule, the automaton is built once
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Calibrator {
+    vocabulary: DigitVocabulary,
+    rule: CalibrationRule
+}
+
+impl Calibrator {
+    pub fn new(vocabulary: &DigitVocabulary, rule: CalibrationRule) -> Result<Calibrator, String> {
+        if rule.count == 0 {
+            return Err("at least one digit is required".to_string());
+        }
+
+        Ok(Calibrator { vocabulary: vocabulary.with_radix(rule.radix)?, rule })
+    }
+
+    pub fn vocabulary(&self) -> &DigitVocabulary {
+        &self.vocabulary
+    }
+
+    pub fn rule(&self) -> CalibrationRule {
+        self.rule
+    }
+
+    pub fn value(&self, line: &str) -> Result<u64, &'static str> {
+        self.rule.value(&self.vocabulary.find_tokens(line))
+    }
+
+    // fails with the first line without a value
+    pub fn sum(&self, lines: &[String]) -> io::Result<u64> {
+        let mut sum = 0u64;
+
+        for (index, line) in lines.iter().enumerate() {
+            let value = self.value(line).map_err(|err| io::Error::other(format!("line {}: {}", index+1, err)))?;
+
+            sum = sum.checked_add(value).ok_or(io::Error::other(format!("line {}: sum out of range", index+1)))?;
+        }
+
+        Ok(sum)
+    }
+}
+
+// the digits of each value in the radix one after the other, None beyond u64
+fn concatenate(values: impl IntoIterator<Item = u8>, radix: u32) -> Option<u64> {
+    values.into_iter().try_fold(0u64, |concatenated, value| {
+        let mut shift = radix as u64;
+        while shift <= value as u64 {
+            shift *= radix as u64;
+        }
+
+        concatenated.checked_mul(shift)?.checked_add(value as u64)
+    })
+}
+
+fn english_vocabulary() -> &'static DigitVocabulary {
+    static VOCABULARY: OnceLock<DigitVocabulary> = OnceLock::new();
+
+    VOCABULARY.get_or_init(DigitVocabulary::english)
+}
+
+pub fn sum_calibration_values(lines: &[String], process_line: impl Fn(&str) -> Result<u8, &str>) -> io::Result<String> {
+    let mut sum = 0u32;
+
+    for (index, line) in lines.iter().enumerate() {
+        let value = 
+            process_line(line)
+                .map_err(|err| io::Error::other(format!("line {}: {}", index+1, err)))?;
+
+        sum += value as u32;
+    }
+
+    Ok(sum.to_string())
+}
+
+pub fn process_line_part1(line : &str) -> Result<u8, &str> {
+    let mut first_digit: Option<u8> = None;
+    let mut last_digit: Option<u8> = None;
+    let mut tokens = Tokens::new(line);
+
+    for char in line.chars() {
+        let mut digit: Option<u8> = None;
+        
+        if let Some(value) = char.to_digit(10) {
+            digit = Some(value as u8);
+            tokens.push(&char.to_string());
+        }
+
+        if let Some(digit) = digit {
+            if first_digit.is_none() {
+                first_digit = Some(digit);
+            }
+            
+            last_digit = Some(digit);
+        }
+    }
+
+    let value =
+        if let Some(first_digit) = first_digit {
+            Ok(first_digit*10 + last_digit.unwrap())
+        } else {
+            Err("no digits")
+        };
+
+    tokens.trace(value);
+
+    value
+}
+
+pub fn process_line_part2(line : &str) -> Result<u8, &str> {
+    process_line_with_vocabulary(line, english_vocabulary())
+}
+
+// like part 2, with the digit words of the vocabulary
+//
+// words may overlap, as in "eightwo", and where several words start at the same character the longest one counts;
+// values of numbers beyond u8 fail, a Calibrator reads them
+pub fn process_line_with_vocabulary<'a>(line: &'a str, vocabulary: &DigitVocabulary) -> Result<u8, &'a str> {
+    let value =
+        if let Some((first_digit, last_digit)) = vocabulary.first_and_last_digit(line) {
+            concatenate([first_digit, last_digit], vocabulary.radix)
+                .and_then(|value| u8::try_from(value).ok())
+                .ok_or("calibration value out of range")
+        } else {
+            Err("no digits")
+        };
+
+    if trace::enabled(1) {
+        let mut tokens = Tokens::new(line);
+        for token in vocabulary.find_tokens(line) {
+            tokens.push(&line[token.span]);
+        }
+
+        tokens.trace(value);
+    }
+
+    value
+}
+
+// digit tokens matched in a line, only collected while tracing
+struct Tokens<'a> {
+    line: &'a str,
+    tokens: Option<Vec<String>>
+}
+
+impl<'a> Tokens<'a> {
+    fn new(line: &'a str) -> Tokens<'a> {
+        Tokens { line, tokens: trace::enabled(1).then(Vec::new) }
+    }
+
+    fn push(&mut self, token: &str) {
+        if let Some(tokens) = &mut self.tokens {
+            tokens.push(token.to_string());
+        }
+    }
+
+    fn trace(self, value: Result<u8, &str>) {
+        if let Some(tokens) = self.tokens {
+            match value {
+                Ok(value) => crate::trace!(1, "'{}': {} -> {}", self.line, tokens.join(" "), value),
+                Err(err) => crate::trace!(1, "'{}': {}", self.line, err)
+            }
+        }
+    }
+}
+
+// generates `size` calibration lines, each of them has at least one digit
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut tokens: Vec<String> =
+                (0..rng.range(1..8))
+                    .map(|_|
+                        match rng.range(0..3) {
+                            0 => rng.range(1..10).to_string(),
+                            1 => rng.pick(&ENGLISH).to_string(),
+                            _ => (0..rng.range(1..5)).map(|_| (b'a' + rng.range(0..26) as u8) as char).collect()
+                        })
+                    .collect();
+
+            let index = rng.index(tokens.len() + 1);
+            tokens.insert(index, rng.range(1..10).to_string());
+
+            tokens.concat()
+        })
+        .collect()
+}
+
+// keeps the digit words where they are, permutes the digits and replaces the other letters
+//
+// none of the replacement letters occurs in a digit word, so they can't form a new one
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    const FILLERS: [char; 12] = ['a', 'b', 'c', 'd', 'j', 'k', 'l', 'm', 'p', 'q', 'y', 'z'];
+
+    let mut digits: Vec<char> = ('1'..='9').collect();
+    rng.shuffle(&mut digits);
+
+    Ok(
+        lines.iter()
+            .map(|line| {
+                let mut in_word = vec![false; line.len()];
+                for index in 0..line.len() {
+                    for word in ENGLISH {
+                        if line.as_bytes()[index..].starts_with(word.as_bytes()) {
+                            in_word[index..index + word.len()].fill(true);
+                        }
+                    }
+                }
+
+                line.char_indices()
+                    .map(|(index, c)| match c {
+                        '1'..='9' => digits[c as usize - '1' as usize],
+                        c if c.is_ascii_alphabetic() && !in_word[index] => *rng.pick(&FILLERS),
+                        c => c
+                    })
+                    .collect()
+            })
+            .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    mod process_line_part1 {
+        use crate::day01::process_line_part1;
+
+        #[test]
+        fn it_fails_without_any_digits() {
+            assert_eq!(process_line_part1("aWord"), Err("no digits"))
+        }
+
+        #[test]
+        fn it_concatenates_the_only_digit_twice() {
+            assert_eq!(process_line_part1("word1word"), Ok(11))
+        }
+
+        #[test]
+        fn it_concatenates_first_digit_with_last_digit() {
+            assert_eq!(process_line_part1("word1word2word3word"), Ok(13))
+        }
+    }
+
+    mod process_line_part2 {
+        use crate::day01::process_line_part2;
+        use crate::trace;
+
+        #[test]
+        fn it_fails_without_any_digits() {
+            assert_eq!(process_line_part2("aWord"), Err("no digits"))
+        }
+
+        #[test]
+        fn it_concatenates_the_only_digit_twice() {
+            assert_eq!(process_line_part2("word1word"), Ok(11))
+        }
+
+        #[test]
+        fn it_concatenates_first_digit_with_last_digit() {
+            assert_eq!(process_line_part2("word1word2word3word"), Ok(13))
+        }
+
+        #[test]
+        fn it_recognizes_words_as_digit() {
+            assert_eq!(process_line_part2("one2three"), Ok(13))
+        }
+
+        #[test]
+        fn it_traces_the_matched_tokens() {
+            let (_, lines) = trace::capture(1, || {
+                process_line_part2("xtwone3four").unwrap();
+                process_line_part2("abc").unwrap_err();
+            });
+
+            assert_eq!(vec!["'xtwone3four': two one 3 four -> 24", "'abc': no digits"], lines);
+        }
+    }
+
+    mod process_line_with_vocabulary {
+        use crate::day01::{process_line_with_vocabulary, CalibrationRule, Calibrator, DigitVocabulary};
+
+        #[test]
+        fn it_recognizes_overlapping_words() {
+            assert_eq!(process_line_with_vocabulary("eightwo", &DigitVocabulary::english()), Ok(82));
+            assert_eq!(process_line_with_vocabulary("zweins", &DigitVocabulary::german()), Ok(21));
+        }
+
+        #[test]
+        fn it_recognizes_words_inside_longer_words() {
+            assert_eq!(process_line_with_vocabulary("dreizehn", &DigitVocabulary::german()), Ok(33));
+            assert_eq!(process_line_with_vocabulary("xfünfundsiebzig", &DigitVocabulary::german()), Ok(55));
+        }
+
+        #[test]
+        fn it_prefers_the_longest_word_starting_at_a_character() {
+            let vocabulary = DigitVocabulary::parse("1: un\n7: unsept").unwrap();
+
+            assert_eq!(process_line_with_vocabulary("unsept", &vocabulary), Ok(77));
+            assert_eq!(process_line_with_vocabulary("unsept", &DigitVocabulary::french()), Ok(17));
+        }
+
+        #[test]
+        fn it_only_recognizes_the_words_of_the_vocabulary() {
+            assert_eq!(process_line_with_vocabulary("one2three", &DigitVocabulary::spanish()), Ok(22));
+            assert_eq!(process_line_with_vocabulary("nueveone", &DigitVocabulary::english()), Ok(11));
+        }
+
+        #[test]
+        fn it_agrees_with_the_calibrator_in_other_radixes() {
+            let vocabulary = DigitVocabulary::english().with_radix(16).unwrap();
+            let calibrator = Calibrator::new(&vocabulary, CalibrationRule { count: 1, radix: 16 }).unwrap();
+
+            for line in ["one", "five", "seven", "eighte", "xFa3b", "ninetwo"] {
+                assert_eq!(calibrator.value(line), process_line_with_vocabulary(line, &vocabulary).map(|value| value as u64), "{}", line);
+            }
+            assert_eq!(Ok(0x11), process_line_with_vocabulary("one", &vocabulary));
+        }
+    }
+
+    mod digit_vocabulary {
+        use std::ops::Range;
+
+        use crate::day01::{generate, DigitVocabulary};
+        use crate::parse_error::{ParseError, ParseErrorKind};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_parse_digits_and_words() {
+            let vocabulary = DigitVocabulary::parse("# roman\n\n1: i one\n5: v\n").unwrap();
+
+            assert_eq!(vec![("i", 1), ("one", 1), ("v", 5)], vocabulary.words().collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn it_should_reject_invalid_lines() {
+            assert_eq!(
+                Err(ParseError::new(2, 4, ParseErrorKind::MissingDelimiter(":"))),
+                DigitVocabulary::parse("1: i\nten")
+            );
+            assert_eq!(
+                Err(ParseError::new(1, 1, ParseErrorKind::Unexpected { expected: "a digit from 0 to 35", found: "36".to_string() })),
+                DigitVocabulary::parse("36: x")
+            );
+            assert_eq!(
+                Err(ParseError::new(1, 4, ParseErrorKind::Missing("word"))),
+                DigitVocabulary::parse("1: ")
+            );
+            assert_eq!(
+                Err(ParseError::new(1, 4, ParseErrorKind::Unexpected { expected: "a word without digits", found: "x1".to_string() })),
+                DigitVocabulary::parse("1: x1")
+            );
+            assert_eq!(
+                Err(ParseError::new(2, 4, ParseErrorKind::Unexpected { expected: "a word of another digit", found: "i".to_string() })),
+                DigitVocabulary::parse("1: i\n2: i")
+            );
+        }
+
+        #[test]
+        fn it_should_combine_languages() {
+            let combined = DigitVocabulary::combine(&[DigitVocabulary::english(), DigitVocabulary::french()]).unwrap();
+
+            assert_eq!(17, combined.words().count(), "six is shared");
+            assert_eq!(Some(("huit", 8)), combined.match_at("huitre"));
+            assert_eq!(
+                Err("'un' is not a word of another digit".to_string()),
+                DigitVocabulary::combine(&[DigitVocabulary::french(), DigitVocabulary::parse("2: un").unwrap()])
+            );
+        }
+
+        #[test]
+        fn it_should_find_the_digits_a_scan_of_every_character_finds() {
+            let vocabulary = DigitVocabulary::combine(&[DigitVocabulary::english(), DigitVocabulary::french()]).unwrap();
+
+            for line in generate(&mut Rng::new(21), 200) {
+                let expected: Vec<(Range<usize>, u8)> =
+                    line.char_indices()
+                        .filter_map(|(index, char)| match char.to_digit(10) {
+                            Some(digit) => Some((index..index + 1, digit as u8)),
+                            None => vocabulary.match_at(&line[index..]).map(|(word, digit)| (index..index + word.len(), digit))
+                        })
+                        .collect();
+
+                assert_eq!(
+                    expected,
+                    vocabulary.find_tokens(&line).into_iter().map(|token| (token.span, token.value)).collect::<Vec<_>>(),
+                    "{}", line
+                );
+                assert_eq!(
+                    expected.first().zip(expected.last()).map(|((_, first), (_, last))| (*first, *last)),
+                    vocabulary.first_and_last_digit(&line),
+                    "{}", line
+                );
+            }
+        }
+
+        #[test]
+        fn it_should_find_the_builtin_vocabularies_by_name() {
+            for name in DigitVocabulary::BUILTIN {
+                assert_eq!(9, DigitVocabulary::builtin(name).unwrap().words().count());
+            }
+
+            assert_eq!(None, DigitVocabulary::builtin("klingon"));
+        }
+    }
+
+    mod report {
+        use crate::day01::{audit, report_part1, report_part2, report_with_vocabulary, DigitVocabulary, Token, TokenKind};
+
+        #[test]
+        fn it_should_report_every_token_with_its_span() {
+            let report = report_part2("xtwone3four");
+
+            assert_eq!(
+                vec![
+                    Token { span: 1..4, kind: TokenKind::Word, value: 2 },
+                    Token { span: 3..6, kind: TokenKind::Word, value: 1 },
+                    Token { span: 6..7, kind: TokenKind::Digit, value: 3 },
+                    Token { span: 7..11, kind: TokenKind::Word, value: 4 }
+                ],
+                report.tokens
+            );
+            assert_eq!((Some(0), Some(3)), (report.first, report.last));
+            assert_eq!(Ok(24), report.value());
+            assert_eq!("two@1..4 one@3..6 3@6..7 four@7..11, first two, last four -> 24", report.describe("xtwone3four"));
+        }
+
+        #[test]
+        fn it_should_report_lines_without_tokens() {
+            let report = report_part1("abc");
+
+            assert_eq!((vec![], None, None), (report.tokens.clone(), report.first, report.last));
+            assert_eq!(Err("no digits"), report.value());
+            assert_eq!("no digits", report.describe("abc"));
+        }
+
+        #[test]
+        fn it_should_report_byte_spans_of_words_with_multibyte_chars() {
+            let report = report_with_vocabulary("fünf7", &DigitVocabulary::german());
+
+            assert_eq!(vec![0..5, 5..6], report.tokens.iter().map(|token| token.span.clone()).collect::<Vec<_>>());
+            assert_eq!(Ok(57), report.value());
+        }
+
+        #[test]
+        fn it_should_read_the_value_in_the_radix_of_the_vocabulary() {
+            let report = report_with_vocabulary("fxone", &DigitVocabulary::english().with_radix(16).unwrap());
+
+            assert_eq!(16, report.radix);
+            assert_eq!(Ok(0xf1), report.value());
+            assert_eq!("f@0..1 one@2..5, first f, last one -> 241", report.describe("fxone"));
+        }
+
+        #[test]
+        fn it_should_audit_disagreements_between_the_parts() {
+            let lines = vec!["1abc2".to_string(), "xtwone3four".to_string(), "abc".to_string()];
+
+            let audits = audit(&lines, &DigitVocabulary::english());
+
+            assert_eq!(vec![true, false, true], audits.iter().map(|audit| audit.agrees()).collect::<Vec<_>>());
+            assert_eq!((2, Ok(33), Ok(24)), (audits[1].line, audits[1].part1.value(), audits[1].part2.value()));
+        }
+    }
+
+    mod calibrator {
+        use crate::day01::{generate, process_line_part2, CalibrationRule, Calibrator, DigitVocabulary};
+        use crate::random::Rng;
+
+        fn hexadecimal() -> DigitVocabulary {
+            DigitVocabulary::combine(&[
+                DigitVocabulary::english(),
+                DigitVocabulary::parse("10: ten\n11: eleven\n12: twelve\n13: thirteen\n14: fourteen\n15: fifteen").unwrap()
+            ]).unwrap()
+        }
+
+        #[test]
+        fn it_should_read_the_puzzle_rule_like_part_2() {
+            let calibrator = Calibrator::new(&DigitVocabulary::english(), CalibrationRule::PUZZLE).unwrap();
+
+            for line in generate(&mut Rng::new(24), 100) {
+                assert_eq!(process_line_part2(&line).map(|value| value as u64), calibrator.value(&line), "{}", line);
+            }
+        }
+
+        #[test]
+        fn it_should_combine_the_first_and_last_digits() {
+            let calibrator = Calibrator::new(&DigitVocabulary::english(), CalibrationRule { count: 2, radix: 10 }).unwrap();
+
+            assert_eq!(Ok(1289), calibrator.value("1two3456eight9"));
+            assert_eq!(Ok(1212), calibrator.value("x1twox"), "both groups are the only two digits");
+            assert_eq!(Ok(1223), calibrator.value("1two3"), "groups share the middle digit");
+            assert_eq!(Err("too few digits"), calibrator.value("one"));
+            assert_eq!(Err("no digits"), calibrator.value("x"));
+        }
+
+        #[test]
+        fn it_should_read_digits_and_words_in_the_radix() {
+            let calibrator = Calibrator::new(&hexadecimal(), CalibrationRule { count: 1, radix: 16 }).unwrap();
+
+            assert_eq!(Ok(0xf7), calibrator.value("Fx7"));
+            assert_eq!(Ok(0xcd), calibrator.value("twelvexyzd"));
+            assert_eq!(Ok(0x33), calibrator.value("thirty3"), "'thirteen' is not in the line");
+            assert_eq!(Err("no digits"), Calibrator::new(&hexadecimal(), CalibrationRule { count: 1, radix: 11 }).unwrap().value("twelve"));
+        }
+
+        #[test]
+        fn it_should_not_read_the_letters_of_words_as_digits() {
+            let calibrator = Calibrator::new(&DigitVocabulary::english(), CalibrationRule { count: 1, radix: 16 }).unwrap();
+
+            assert_eq!(Ok(0x11), calibrator.value("one"));
+            assert_eq!(Ok(0x55), calibrator.value("five"));
+            assert_eq!(Ok(0x77), calibrator.value("seven"));
+            assert_eq!(Ok(0x8e), calibrator.value("eighte"), "a letter after the word is a digit");
+            assert_eq!(Ok(0x82), calibrator.value("eightwo"), "words still overlap");
+        }
+
+        #[test]
+        fn it_should_fail_for_values_beyond_u64() {
+            let calibrator = Calibrator::new(&DigitVocabulary::english(), CalibrationRule { count: 10, radix: 10 }).unwrap();
+
+            assert_eq!(Ok(12_345_678_901_234_567_890), calibrator.value("1234567890"));
+            assert_eq!(Err("calibration value out of range"), calibrator.value("9234567890"));
+        }
+
+        #[test]
+        fn it_should_reject_invalid_rules() {
+            assert!(Calibrator::new(&DigitVocabulary::english(), CalibrationRule { count: 0, radix: 10 }).is_err());
+            assert!(Calibrator::new(&DigitVocabulary::english(), CalibrationRule { count: 1, radix: 1 }).is_err());
+            assert!(Calibrator::new(&DigitVocabulary::english(), CalibrationRule { count: 1, radix: 37 }).is_err());
+        }
+
+        #[test]
+        fn it_should_fail_for_invalid_rules_applied_directly() {
+            let tokens = DigitVocabulary::english().find_tokens("1two3");
+
+            assert_eq!(Err("at least one digit is required"), CalibrationRule { count: 0, radix: 10 }.value(&tokens));
+            assert_eq!(Err("radix out of range"), CalibrationRule { count: 1, radix: 0 }.value(&tokens));
+            assert_eq!(Err("radix out of range"), CalibrationRule { count: 1, radix: 1 }.value(&tokens));
+            assert_eq!(Err("radix out of range"), CalibrationRule { count: 1, radix: 37 }.value(&tokens));
+            assert_eq!(Ok(13), CalibrationRule::PUZZLE.value(&tokens));
+        }
+
+        #[test]
+        fn it_should_sum_the_lines() {
+            let calibrator = Calibrator::new(&DigitVocabulary::english(), CalibrationRule { count: 2, radix: 10 }).unwrap();
+
+            assert_eq!(1289 + 4545, calibrator.sum(&["1two3456eight9".to_string(), "four5".to_string()]).unwrap());
+            assert_eq!("line 2: too few digits", calibrator.sum(&["12".to_string(), "1".to_string()]).unwrap_err().to_string());
+        }
+    }
+
+    mod english_numbers {
+        use crate::day01::{process_line_part2, process_line_with_vocabulary, report_with_vocabulary, CalibrationRule, Calibrator, DigitVocabulary, Token, TokenKind};
+
+        #[test]
+        fn it_should_read_compound_numbers_as_single_tokens() {
+            let report = report_with_vocabulary("twentythree4onehundred", &DigitVocabulary::english_numbers());
+
+            assert_eq!(
+                vec![
+                    Token { span: 0..11, kind: TokenKind::Number, value: 23 },
+                    Token { span: 11..12, kind: TokenKind::Digit, value: 4 },
+                    Token { span: 12..22, kind: TokenKind::Number, value: 100 }
+                ],
+                report.tokens
+            );
+            assert_eq!(Ok(23100), report.value());
+        }
+
+        #[test]
+        fn it_should_keep_overlapping_words_that_are_not_inside_a_number() {
+            let vocabulary = DigitVocabulary::english_numbers();
+
+            assert_eq!(Ok(82), process_line_with_vocabulary("eightwo", &vocabulary));
+            assert_eq!(Ok(21), process_line_with_vocabulary("twone", &vocabulary));
+            assert_eq!(Err("calibration value out of range"), process_line_with_vocabulary("ninety3", &vocabulary), "903 is beyond u8");
+        }
+
+        #[test]
+        fn it_should_keep_words_inside_other_words() {
+            let vocabulary = DigitVocabulary::combine(&[DigitVocabulary::english_numbers(), DigitVocabulary::parse("7: xthreex").unwrap()]).unwrap();
+
+            assert_eq!(
+                vec![(0..7, TokenKind::Word, 7), (1..6, TokenKind::Word, 3)],
+                vocabulary.find_tokens("xthreex").into_iter().map(|token| (token.span, token.kind, token.value)).collect::<Vec<_>>()
+            );
+            assert_eq!(Ok(73), process_line_with_vocabulary("xthreex", &vocabulary));
+            assert_eq!(Ok(721), Calibrator::new(&vocabulary, CalibrationRule::PUZZLE).unwrap().value("xthreextwentyone"), "words inside numbers are still dropped");
+        }
+
+        #[test]
+        fn it_should_cover_the_numbers_up_to_onehundred() {
+            let vocabulary = DigitVocabulary::english_numbers();
+            let calibrator = Calibrator::new(&vocabulary, CalibrationRule::PUZZLE).unwrap();
+
+            assert_eq!(91, vocabulary.numbers().count());
+            assert_eq!(Ok(1313), calibrator.value("thirteen"));
+            assert_eq!(Ok(4040), calibrator.value("forty"));
+            assert_eq!(Ok(9999), calibrator.value("xninetyniney"));
+            assert_eq!(Ok(7017), calibrator.value("seventy1seventeen"));
+        }
+
+        #[test]
+        fn it_should_leave_the_puzzle_rules_unchanged() {
+            assert_eq!(Ok(33), process_line_part2("twentythree"));
+            assert_eq!(
+                Ok(33),
+                Calibrator::new(&DigitVocabulary::english(), CalibrationRule::PUZZLE).unwrap().value("twentythree")
+            );
+        }
+
+        #[test]
+        fn it_should_read_numbers_in_radix_10_only() {
+            let calibrator = Calibrator::new(&DigitVocabulary::english_numbers(), CalibrationRule { count: 1, radix: 16 }).unwrap();
+
+            assert_eq!(Ok(0x66), calibrator.value("sixty"));
+        }
+
+        #[test]
+        fn it_should_keep_the_numbers_when_combined() {
+            let combined = DigitVocabulary::combine(&[DigitVocabulary::english_numbers(), DigitVocabulary::german()]).unwrap();
+
+            let calibrator = Calibrator::new(&combined, CalibrationRule::PUZZLE).unwrap();
+
+            assert_eq!(Ok(2121), calibrator.value("twentyone"));
+            assert_eq!(Ok(221), calibrator.value("zweiundzwanzigtwentyone"));
+        }
+    }
+
+    mod tokens_command {
+        use crate::Solution;
+        use crate::day01::Day01;
+        use crate::shell::Shell;
+
+        #[test]
+        fn it_should_describe_the_tokens_of_both_parts() {
+            let day = Day01::parse(&["1abc2".to_string(), "xtwone3four".to_string()]).unwrap();
+
+            assert_eq!(
+                Ok("'xtwone3four'\npart 1: 3@6..7, first 3, last 3 -> 33\npart 2: two@1..4 one@3..6 3@6..7 four@7..11, first two, last four -> 24".to_string()),
+                day.execute("tokens 2")
+            );
+            assert!(day.execute("tokens 3").unwrap_err().starts_with("no line 3, there are 2"));
+            assert_eq!(Ok("line 2: part 1 33, part 2 24\n1 of 2 lines differ".to_string()), day.execute("disagreements"));
+        }
+    }
+
+    mod anonymize {
+        use crate::day01::{anonymize, process_line_part2};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_keep_the_digit_words_and_replace_the_other_letters() {
+            let lines = vec!["xtwone3four".to_string(), "sevenine".to_string()];
+
+            let anonymized = anonymize(&mut Rng::new(1), &lines).unwrap();
+
+            assert!(!anonymized[0].starts_with('x'), "x is no replacement letter");
+            assert_eq!("twone", &anonymized[0][1..6]);
+            assert_eq!("four", &anonymized[0][7..]);
+            assert_eq!(Ok(79), process_line_part2(&anonymized[1]));
+            assert_eq!(process_line_part2(&lines[0]).map(|value| value / 10), process_line_part2(&anonymized[0]).map(|value| value / 10));
+        }
+    }
+}
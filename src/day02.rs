@@ -0,0 +1,384 @@
+use std::io;
+
+use crate::Solution;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+
+pub enum Cube {
+    Blue(u32),
+    Green(u32),
+    Red(u32)
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct CubeCount {
+    pub blue: u32,
+    pub green: u32,
+    pub red: u32
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub power_of_minimal_set: u32,
+    pub is_possible: bool
+}
+
+pub struct Day02 {
+    pub games: Vec<Game>
+}
+
+impl Solution for Day02 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let games = 
+            lines.iter()
+                .enumerate()
+                .map(|(index, line)| process_line(line).map_err(|err| err.on_line(index+1)))
+                .collect::<Result<Vec<Game>, ParseError>>()?;
+
+        Ok(Day02 { games })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        let sum_of_possible_game_ids: u32 = self.games.iter()
+            .map(|game| {
+                if game.is_possible {
+                    game.id
+                }
+                else {
+                    0
+                }
+            })
+            .sum();
+
+        Ok(sum_of_possible_game_ids.to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        let sum_of_power_of_minimal_sets: u32 = self.games.iter()
+            .map(|game| {
+                game.power_of_minimal_set
+            })
+            .sum();
+
+        Ok(sum_of_power_of_minimal_sets.to_string())
+    }
+}
+
+pub fn process_line(line: &str) -> Result<Game, ParseError> {
+    let limit = 
+        CubeCount { 
+            blue: 14,
+            green: 13,
+            red: 12
+        };
+
+    let (game_part, sets_part) = 
+        line.split_once(": ")
+            .ok_or(ParseError::at_end(line, ParseErrorKind::MissingDelimiter(": ")))?;
+
+    let game_id = parse_game_id(line, game_part)?;
+    let sets = parse_sets(line, sets_part)?;
+    let is_game_possible = is_game_possible(&sets, limit);
+
+    let minimal_required_set = get_minimal_required_set(&sets);
+    let power_of_minimal_set = 
+        minimal_required_set.blue 
+        * minimal_required_set.green 
+        * minimal_required_set.red;
+
+    Ok(Game { id: game_id, is_possible: is_game_possible, power_of_minimal_set })
+}
+
+pub fn is_game_possible(sets: &[CubeCount], limit: CubeCount) -> bool {
+    let minimal_required_set = get_minimal_required_set(sets);
+
+    if minimal_required_set.blue > limit.blue {
+        return false;
+    }
+
+    if minimal_required_set.green > limit.green {
+        return false;
+    }
+
+    if minimal_required_set.red > limit.red {
+        return false;
+    }
+
+    true
+}
+
+pub fn get_minimal_required_set(sets: &[CubeCount]) -> CubeCount {
+    aggregate_cube_counts(
+        sets, 
+        |is_first_element, aggregated_value, value| {
+            if is_first_element || aggregated_value < value {
+                value
+            } else {
+                aggregated_value
+            }
+        })
+}
+
+fn aggregate_cube_counts(sets: &[CubeCount], aggregate: impl Fn(bool, u32, u32) -> u32) -> CubeCount {
+    let mut is_first_value = true;
+
+    let mut aggregated_cube = 
+        CubeCount{
+            blue: 0,
+            green: 0,
+            red: 0
+        };
+
+    for set in sets {
+        aggregated_cube.blue = aggregate(is_first_value, aggregated_cube.blue, set.blue);
+        aggregated_cube.green = aggregate(is_first_value, aggregated_cube.green, set.green);
+        aggregated_cube.red = aggregate(is_first_value, aggregated_cube.red, set.red);
+
+        is_first_value = false;
+    }
+
+    aggregated_cube
+}
+
+fn parse_game_id(line: &str, game_part: &str) -> Result<u32, ParseError> {
+    let id_part = 
+        game_part
+            .strip_prefix("Game ")
+            .ok_or(ParseError::at(line, game_part, ParseErrorKind::Unexpected { expected: "'Game <id>'", found: game_part.to_string() }))?;
+
+    id_part.parse()
+        .map_err(|_| ParseError::at(line, id_part, ParseErrorKind::InvalidNumber(id_part.to_string())))
+}
+
+fn parse_sets(line: &str, sets_part: &str) -> Result<Vec<CubeCount>, ParseError> {
+    let sets = sets_part.split("; ");
+    let mut result = vec![];
+
+    for set_part in sets {
+        let set = parse_set(line, set_part)?;
+        result.push(set);
+    }
+
+    Ok(result)
+}
+
+fn parse_set(line: &str, set: &str) -> Result<CubeCount, ParseError> {
+    let cubes = set.split(", ");
+    let mut cube_count = 
+        CubeCount {
+            blue: 0,
+            green: 0,
+            red: 0
+        };
+
+    for cube_part in cubes {
+        if cube_part.is_empty() {
+            continue;
+        }
+
+        let cube = parse_cube(line, cube_part)?;
+
+        match cube {
+            Cube::Blue(count) 
+                => cube_count.blue = count,
+            Cube::Green(count) 
+                => cube_count.green = count,
+            Cube::Red(count) 
+                => cube_count.red = count
+        };
+    }
+
+    Ok(cube_count)
+}
+
+fn parse_cube(line: &str, cube_part: &str) -> Result<Cube, ParseError> {
+    let (count_part, color_part) = 
+        cube_part.split_once(" ")
+            .ok_or(ParseError::at(line, &cube_part[cube_part.len()..], ParseErrorKind::MissingDelimiter(" ")))?;
+
+    let count: u32 = 
+        count_part.parse()
+            .map_err(|_| ParseError::at(line, count_part, ParseErrorKind::InvalidNumber(count_part.to_string())))?;
+
+    match color_part {
+        "blue" => Ok(Cube::Blue(count)),
+        "green" => Ok(Cube::Green(count)),
+        "red" => Ok(Cube::Red(count)),
+        _ => Err(ParseError::at(line, color_part, ParseErrorKind::Unexpected { expected: "blue, green or red", found: color_part.to_string() }))
+    }
+}
+
+// generates `size` games with up to six sets of cubes each
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (1..=size)
+        .map(|id| {
+            let sets: Vec<String> =
+                (0..rng.range(1..7))
+                    .map(|_| {
+                        let mut colors = ["blue", "green", "red"];
+                        rng.shuffle(&mut colors);
+
+                        colors[..rng.index(3) + 1].iter()
+                            .map(|color| format!("{} {}", rng.range(1..21), color))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    })
+                    .collect();
+
+            format!("Game {}: {}", id, sets.join("; "))
+        })
+        .collect()
+}
+
+// same games with the same colors in each set, but with other counts in a random order
+//
+// the counts stay below the largest count of the input
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    for (index, line) in lines.iter().enumerate() {
+        process_line(line).map_err(|err| err.on_line(index+1))?;
+    }
+
+    let largest_count =
+        lines.iter()
+            .flat_map(|line| line.split([':', ';', ',']).skip(1))
+            .filter_map(|cube| cube.split_whitespace().next()?.parse::<u64>().ok())
+            .max()
+            .unwrap_or(1)
+            .max(1);
+
+    Ok(
+        lines.iter()
+            .map(|line| {
+                let (game_part, sets_part) = line.split_once(": ").expect("the line was parsed");
+
+                let sets: Vec<String> =
+                    sets_part.split("; ")
+                        .map(|set| {
+                            let mut colors: Vec<&str> =
+                                set.split(", ")
+                                    .filter_map(|cube_part| cube_part.split_once(" "))
+                                    .map(|(_, color)| color)
+                                    .collect();
+                            rng.shuffle(&mut colors);
+
+                            colors.iter()
+                                .map(|color| format!("{} {}", rng.range(1..largest_count + 1), color))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        })
+                        .collect();
+
+                format!("{}: {}", game_part, sets.join("; "))
+            })
+            .collect())
+}
+
+#[cfg(test)]
+mod test {
+    mod get_minimal_required_set {
+        use crate::day02::{get_minimal_required_set, CubeCount};
+
+        #[test]
+        fn it_determines_the_min_required_cube_counts_per_color() {
+            assert_eq!(
+                CubeCount { blue: 3, green: 4, red: 5 },
+                get_minimal_required_set(&[
+                    CubeCount { blue: 3, green: 1, red: 1 },
+                    CubeCount { blue: 1, green: 4, red: 1 },
+                    CubeCount { blue: 1, green: 1, red: 5 },
+                ])
+            );
+        }
+    }
+
+    mod process_line {
+        use crate::day02::process_line;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_parses_game_id() {
+            assert_eq!(
+                23,
+                process_line("Game 23: ").unwrap().id,
+                "Game 23"
+            );
+            assert_eq!(
+                42,
+                process_line("Game 42: ").unwrap().id,
+                "Game 42"
+            );
+        }
+
+        #[test]
+        fn it_recognizes_possible_games() {
+            assert!(
+                process_line("Game 1: 14 blue, 13 green, 12 red").unwrap().is_possible,
+                "possible game with one subset"
+            );
+            assert!(
+                process_line("Game 1: 14 blue; 13 green; 12 red").unwrap().is_possible,
+                "possible game with three subsets"
+            );
+        }
+
+        #[test]
+        fn it_recognizes_impossible_games() {
+            assert!(
+                !process_line("Game 1: 15 blue").unwrap().is_possible,
+                "impossible game with too many blue cubes"
+            );
+            assert!(
+                !process_line("Game 1: 14 green").unwrap().is_possible,
+                "impossible game with too many green cubes"
+            );
+            assert!(
+                !process_line("Game 1: 13 red").unwrap().is_possible,
+                "impossible game with too many red cubes"
+            );
+            assert!(
+                !process_line("Game 1: 14 blue; 13 green; 13 red").unwrap().is_possible,
+                "impossible game with three subsets"
+            );
+        }
+
+        #[test]
+        fn it_calculates_power_of_minimal_set() {
+
+            assert_eq!(
+                1,
+                process_line("Game 1: 1 blue, 1 green, 1 red").unwrap().power_of_minimal_set,
+                "Game consisting of a single set"
+            );
+
+            assert_eq!(
+                3*4*5,
+                process_line("Game 1: 3 blue, 1 green, 1 red; 1 blue, 4 green, 1 red; 1 blue, 1 green, 5 red").unwrap().power_of_minimal_set,
+                "Game consisting of three sets"
+            );
+        }
+
+        #[test]
+        fn it_reports_the_position_of_invalid_input() {
+            assert_eq!(
+                Err(ParseError::new(1, 26, ParseErrorKind::Unexpected { expected: "blue, green or red", found: "purple".to_string() })),
+                process_line("Game 1: 3 blue; 4 red, 2 purple"),
+                "unknown cube color"
+            );
+
+            assert_eq!(
+                Err(ParseError::new(1, 7, ParseErrorKind::MissingDelimiter(": "))),
+                process_line("Game 1"),
+                "missing separator between game and sets"
+            );
+
+            assert_eq!(
+                Err(ParseError::new(1, 9, ParseErrorKind::InvalidNumber("x".to_string()))),
+                process_line("Game 1: x blue"),
+                "invalid cube count"
+            );
+        }
+    }
+}
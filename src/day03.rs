@@ -0,0 +1,531 @@
+use std::io as io;
+use std::ops::Range;
+
+use crate::Solution;
+use crate::grid::{Grid, Position};
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::render::{self, Color};
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct ParsedItem {
+    pub value: AstItem,
+    pub range: Range<usize>
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum AstItem {
+    Number { value: u32 },
+    Symbol { value: char },
+    Dot
+}
+
+pub struct Day03 {
+    pub schematic: Grid<char>,
+    pub numbers: Vec<SchematicNumber>
+}
+
+// number of the engine schematic and the cells it covers
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct SchematicNumber {
+    pub value: u32,
+    pub y: usize,
+    pub range: Range<usize>
+}
+
+impl SchematicNumber {
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.range.clone().map(|x| (x, self.y))
+    }
+}
+
+impl Solution for Day03 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let (schematic, numbers) = parse_schematic(lines)?;
+
+        Ok(Day03 { schematic, numbers })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        let part_numbers = get_part_numbers(&self.schematic, &self.numbers);
+
+        Ok(part_numbers.into_iter().sum::<u32>().to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        let wheels = get_wheels(&self.schematic, &self.numbers);
+
+        Ok(
+            wheels.into_iter()
+                .map(|wheel| {wheel.0 * wheel.1})
+                .sum::<u32>()
+                .to_string()
+        )
+    }
+}
+
+pub fn parse_schematic(lines: &[String]) -> Result<(Grid<char>, Vec<SchematicNumber>), ParseError> {
+    let mut numbers = vec![];
+
+    for (y, line) in lines.iter().enumerate() {
+        for item in parse_line(line).map_err(|err| err.on_line(y+1))? {
+            if let AstItem::Number { value } = item.value {
+                // item ranges are byte offsets, the grid counts characters
+                let x = line[..item.range.start].chars().count();
+
+                numbers.push(SchematicNumber { value, y, range: x..x + item.range.len() });
+            }
+        }
+    }
+
+    Ok((Grid::parse(lines)?, numbers))
+}
+
+pub fn get_part_numbers(schematic: &Grid<char>, numbers: &[SchematicNumber]) -> Vec<u32> {
+    numbers.iter()
+        .filter(|number| {
+            let symbol = adjacent_symbol(schematic, number);
+
+            match symbol {
+                Some((x, y)) => crate::trace!(1, "{} at line {}, columns {}-{}: part number, adjacent to '{}' at line {}, column {}",
+                    number.value, number.y+1, number.range.start+1, number.range.end, schematic[(x, y)], y+1, x+1),
+                None => crate::trace!(1, "{} at line {}, columns {}-{}: no part number, no adjacent symbol",
+                    number.value, number.y+1, number.range.start+1, number.range.end)
+            }
+
+            symbol.is_some()
+        })
+        .map(|number| number.value)
+        .collect()
+}
+
+pub fn adjacent_symbol(schematic: &Grid<char>, number: &SchematicNumber) -> Option<Position> {
+    number.positions()
+        .flat_map(|position| schematic.neighbors8(position))
+        .find(|position| is_symbol(schematic[*position]))
+}
+
+// part numbers and other numbers in different colors, symbols highlighted
+pub fn render(schematic: &Grid<char>, numbers: &[SchematicNumber]) -> Grid<Color> {
+    let mut tiles = schematic.map(|c| if is_symbol(*c) { render::HIGHLIGHT } else { render::BACKGROUND });
+
+    for number in numbers {
+        let color = if adjacent_symbol(schematic, number).is_some() { render::POSITIVE } else { render::NEGATIVE };
+
+        for position in number.positions() {
+            tiles[position] = color;
+        }
+    }
+
+    tiles
+}
+
+pub fn get_wheels(schematic: &Grid<char>, numbers: &[SchematicNumber]) -> Vec<(u32, u32)> {
+    // index of the number covering each cell
+    let mut number_at = schematic.map(|_| None);
+    for (index, number) in numbers.iter().enumerate() {
+        for position in number.positions() {
+            number_at[position] = Some(index);
+        }
+    }
+
+    schematic.positions()
+        .filter(|position| is_symbol(schematic[*position]))
+        .filter_map(|position| {
+            let mut adjacent_numbers: Vec<usize> = vec![];
+
+            for index in schematic.neighbors8(position).filter_map(|neighbor| number_at[neighbor]) {
+                if !adjacent_numbers.contains(&index) {
+                    adjacent_numbers.push(index);
+                }
+            }
+
+            if adjacent_numbers.len() == 2 {
+                Some((numbers[adjacent_numbers[0]].value, numbers[adjacent_numbers[1]].value))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn is_symbol(c: char) -> bool {
+    !c.is_ascii_digit() && c != '.'
+}
+
+pub fn parse_line(line: &str) -> Result<Vec<ParsedItem>, ParseError> {
+    let mut items = vec![];
+
+    let mut char_indices_iterator = line.char_indices().peekable();
+
+    while let Some(mut index_and_character) = char_indices_iterator.next() {
+        let mut range: Range<usize> = index_and_character.0..index_and_character.0 + 1;
+
+        let parsed_item = 
+            if is_symbol(index_and_character.1) {
+                Ok(ParsedItem { 
+                    value: AstItem::Symbol { value: index_and_character.1 }, 
+                    range
+                })
+            } else if index_and_character.1 == '.' {
+                Ok(ParsedItem {
+                    value: AstItem::Dot,
+                    range
+                })
+            } else if index_and_character.1.is_ascii_digit() {
+                while index_and_character.1.is_ascii_digit() {
+                    range.end = index_and_character.0 + 1;
+        
+                    match char_indices_iterator.peek() {
+                        Some(c) => {
+                            // prevent consuming the first non-digit character when trying to parse a number
+                            if !c.1.is_ascii_digit() {
+                                break;
+                            }
+                            
+                            index_and_character = char_indices_iterator.next().unwrap();
+                        }
+                        None => { break }
+                    }
+                }
+    
+                let number = &line[range.clone()];
+                match number.parse() {
+                    Ok(value) => 
+                        Ok(ParsedItem {
+                            value: AstItem::Number { value },
+                            range
+                        }),
+                    Err(_) => Err(ParseError::at(line, number, ParseErrorKind::InvalidNumber(number.to_string())))
+                }
+            } else {
+                let token = &line[range];
+                Err(ParseError::at(line, token, ParseErrorKind::Unexpected { expected: "digit, '.' or symbol", found: token.to_string() }))
+            };
+
+        items.push(parsed_item?);
+    }
+
+    Ok(items)
+}
+
+// the gear symbol comes first
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '%', '=', '&', '-'];
+
+// generates a schematic of `size` x `size` cells with numbers of up to three digits
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+
+            while line.len() < size {
+                let digits = rng.range(1..4) as usize;
+
+                if rng.chance(15) && line.len() + digits <= size {
+                    line += &rng.range(10u64.pow(digits as u32 - 1)..10u64.pow(digits as u32)).to_string();
+
+                    // keeps the number apart from the next one
+                    if line.len() < size {
+                        line.push(if rng.chance(20) { *rng.pick(&SYMBOLS) } else { '.' });
+                    }
+                } else if rng.chance(8) {
+                    line.push(*rng.pick(&SYMBOLS));
+                } else {
+                    line.push('.');
+                }
+            }
+
+            line
+        })
+        .collect()
+}
+
+// numbers with the same number of digits and other symbols at the same places, possibly upside down
+//
+// gears stay gears, the other symbols are replaced with random symbols that are no gear
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    parse_schematic(lines)?;
+
+    let mut anonymized: Vec<String> =
+        lines.iter()
+            .map(|line| {
+                let mut previous_is_digit = false;
+
+                line.chars()
+                    .map(|c| {
+                        let anonymized =
+                            match c {
+                                // no leading zeros
+                                '0'..='9' if previous_is_digit => char::from(b'0' + rng.range(0..10) as u8),
+                                '0'..='9' => char::from(b'0' + rng.range(1..10) as u8),
+                                '.' | '*' => c,
+                                _ => *rng.pick(&SYMBOLS[1..])
+                            };
+                        previous_is_digit = c.is_ascii_digit();
+
+                        anonymized
+                    })
+                    .collect()
+            })
+            .collect();
+
+    if rng.chance(50) {
+        anonymized.reverse();
+    }
+
+    Ok(anonymized)
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::day03::{parse_schematic, SchematicNumber};
+    use crate::grid::Grid;
+
+    fn schematic(lines: &[&str]) -> (Grid<char>, Vec<SchematicNumber>) {
+        parse_schematic(&lines.iter().map(|line| line.to_string()).collect::<Vec<String>>()).unwrap()
+    }
+
+    mod parse_line {
+        use crate::day03::{parse_line, AstItem, ParsedItem};
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_parses_a_single_token() {
+            assert_eq!(
+                vec![ParsedItem { 
+                        value: AstItem::Number { value: 42 }, 
+                        range: 0..2}],
+
+                parse_line("42").unwrap(), 
+                "number"
+            );
+
+            assert_eq!(
+                vec![ParsedItem { 
+                        value: AstItem::Symbol { value: '*' }, 
+                        range: 0..1}],
+
+                parse_line("*").unwrap(), 
+                "* symbol"
+            );
+
+            assert_eq!(
+                vec![ParsedItem { 
+                        value: AstItem::Dot, 
+                        range: 0..1}],
+
+                parse_line(".").unwrap(), 
+                "dot"
+            );
+        }
+
+        #[test]
+        fn it_parses_multiple_tokens() {
+            assert_eq!(
+                vec![
+                    ParsedItem { value: AstItem::Dot, range: 0..1},
+                    ParsedItem { value: AstItem::Dot, range: 1..2},
+                    ParsedItem { value: AstItem::Symbol { value: '$' }, range: 2..3 },
+                    ParsedItem { 
+                        value: AstItem::Number { value: 42 }, 
+                        range: 3..5},
+                    ParsedItem { value: AstItem::Symbol { value: '*' }, range: 5..6 },
+                    ParsedItem { value: AstItem::Dot, range: 6..7},
+                    ParsedItem { value: AstItem::Dot, range: 7..8}
+                ],
+
+                parse_line("..$42*..").unwrap(), 
+                "single number enclosed with symbols or dots"
+            );
+
+            assert_eq!(
+                vec![
+                    ParsedItem { 
+                        value: AstItem::Number { value: 1 }, 
+                        range: 0..1},
+                    ParsedItem { value: AstItem::Dot, range: 1..2},
+                    ParsedItem { value: AstItem::Dot, range: 2..3},
+                    ParsedItem { value: AstItem::Symbol { value: '$' }, range: 3..4 },
+                    ParsedItem { 
+                        value: AstItem::Number { value: 23 }, 
+                        range: 4..6},
+                    ParsedItem { value: AstItem::Symbol { value: '*' }, range: 6..7 },
+                    ParsedItem { value: AstItem::Dot, range: 7..8},
+                    ParsedItem { value: AstItem::Dot, range: 8..9},
+                    ParsedItem { 
+                        value: AstItem::Number { value: 42 }, 
+                        range: 9..11}
+                ],
+                
+                parse_line("1..$23*..42").unwrap(), 
+                "multiple numbers mixed with symbols or dots"
+            );
+        }
+
+        #[test]
+        fn it_reports_the_position_of_numbers_that_are_too_large() {
+            assert_eq!(
+                Err(ParseError::new(1, 3, ParseErrorKind::InvalidNumber("99999999999".to_string()))),
+
+                parse_line("..99999999999*")
+            );
+        }
+    }
+
+    mod get_part_numbers {
+        use crate::day03::get_part_numbers;
+        use crate::day03::test::schematic;
+        use crate::trace;
+
+        #[test]
+        fn it_returns_numbers_adjacent_to_a_symbol() {
+            let (grid, numbers) = schematic(&["2"]);
+            assert_eq!(
+                vec![0u32; 0],
+
+                get_part_numbers(&grid, &numbers),
+                "single non-part number"
+            );
+
+            let (grid, numbers) = schematic(&["*2"]);
+            assert_eq!(
+                vec![2],
+
+                get_part_numbers(&grid, &numbers),
+                "single part number"
+            );
+
+            let (grid, numbers) = schematic(&["1.2*3.4"]);
+            assert_eq!(
+                vec![2, 3],
+
+                get_part_numbers(&grid, &numbers),
+                "single line with multiple numbers"
+            );
+
+            let (grid, numbers) = schematic(&[
+                "......*",
+                ".1.2.3.",
+                "$......"
+            ]);
+            assert_eq!(
+                vec![1, 3],
+
+                get_part_numbers(&grid, &numbers),
+                "multiple lines with some part numbers"
+            );
+        }
+
+        #[test]
+        fn it_traces_why_numbers_are_accepted() {
+            let (grid, numbers) = schematic(&[
+                "......*",
+                ".1.2.23"
+            ]);
+
+            let (_, lines) = trace::capture(1, || get_part_numbers(&grid, &numbers));
+
+            assert_eq!(
+                vec![
+                    "1 at line 2, columns 2-2: no part number, no adjacent symbol",
+                    "2 at line 2, columns 4-4: no part number, no adjacent symbol",
+                    "23 at line 2, columns 6-7: part number, adjacent to '*' at line 1, column 7"
+                ],
+                lines
+            );
+        }
+
+        mod get_wheels {
+            use crate::day03::get_wheels;
+            use crate::day03::test::schematic;
+
+            #[test]
+            fn it_returns_a_single_wheel() {
+                let (grid, numbers) = schematic(&["23*42"]);
+                assert_eq!(
+                    vec![
+                        (23, 42)
+                    ],
+
+                    get_wheels(&grid, &numbers),
+                    "single wheel within the same line"
+                );
+
+                let (grid, numbers) = schematic(&[
+                    "42....",
+                    "..*...",
+                    "...123"
+                ]);
+                assert_eq!(
+                    vec![
+                        (42, 123)
+                    ],
+
+                    get_wheels(&grid, &numbers),
+                    "single wheel with upper left and lower right number"
+                );
+
+                let (grid, numbers) = schematic(&[
+                    "...123",
+                    "..*...",
+                    "42...."
+                ]);
+                assert_eq!(
+                    vec![
+                        (123, 42)
+                    ],
+
+                    get_wheels(&grid, &numbers),
+                    "single wheel with upper right and lower left number"
+                );
+            }
+
+            #[test]
+            fn it_returns_multiple_wheels() {
+                let (grid, numbers) = schematic(&[
+                    "1..42.....6",
+                    ".*...*....*",
+                    "2.3...123.."
+                ]);
+                assert_eq!(
+                    vec![
+                        (42, 123)
+                    ],
+
+                    get_wheels(&grid, &numbers),
+                    "wheel enclosed in non-wheels"
+                );
+            }
+        }
+    }
+
+    mod render {
+        use crate::day03::render;
+        use crate::day03::test::schematic;
+        use crate::grid::Grid;
+        use crate::render::{BACKGROUND as B, HIGHLIGHT as S, NEGATIVE as N, POSITIVE as P};
+
+        #[test]
+        fn it_should_color_part_numbers_and_other_numbers_differently() {
+            let (grid, numbers) = schematic(&[
+                "12.*",
+                "..34",
+                "5..."
+            ]);
+
+            assert_eq!(
+                Grid::from_rows(vec![
+                    vec![N, N, B, S],
+                    vec![B, B, P, P],
+                    vec![N, B, B, B]
+                ]).unwrap(),
+
+                render(&grid, &numbers)
+            );
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::{BTreeSet, HashSet};
+use std::io;
+
+use crate::Solution;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+
+pub struct ScratchCardGame {
+    pub winning_numbers: HashSet<u32>,
+
+    // we want to iterate over numbers on card
+    // this is cheaper on a BTreeSet than on a sparsely filled HashSet
+    pub numbers_on_card: BTreeSet<u32>
+}
+
+impl ScratchCardGame{
+    pub fn get_number_of_winning_bets(&self) -> usize {
+        self.numbers_on_card.iter()
+            .filter(|number| self.winning_numbers.contains(number))
+            .count()
+    }
+
+    pub fn get_worth(&self) -> usize {
+        let number_of_winning_bets = self.get_number_of_winning_bets();
+
+        if number_of_winning_bets > 0 {
+            1<<(number_of_winning_bets-1)
+        } else {
+            0
+        }
+    }
+}
+
+pub struct Day04 {
+    pub cards: Vec<ScratchCardGame>
+}
+
+impl Solution for Day04 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let mut cards = vec![];
+
+        for (index, line) in lines.iter().enumerate() {
+            let game = parse_game(line).map_err(|err| err.on_line(index+1))?;
+
+            cards.push(game);
+        }
+
+        Ok(Day04 { cards })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        Ok(
+            self.cards
+                .iter()
+                .map( |game| game.get_worth() )
+                .sum::<usize>()
+                .to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        let cards_count_and_worth = calculate_number_of_cards(&self.cards);
+
+        Ok(
+            cards_count_and_worth
+                .iter()
+                .map( |count_and_card| count_and_card.0 )
+                .sum::<usize>()
+                .to_string())
+    }
+}
+
+pub fn calculate_number_of_cards(cards: &[ScratchCardGame]) -> Vec<(usize, &ScratchCardGame)> {
+    let mut count_and_cards = vec![];
+
+    for card in cards {
+        count_and_cards.push((1, card));
+    }
+
+    for i in 0..cards.len() {
+        let worth = cards[i].get_number_of_winning_bets();
+
+        for j in i+1..i+1+worth {
+            count_and_cards[j].0 += count_and_cards[i].0;
+        }
+    }
+
+    count_and_cards
+}
+
+pub fn parse_game(line: &str) -> Result<ScratchCardGame, ParseError> {
+    // skip card id
+    let (_, winning_numbers_and_numbers_on_card) =
+        line.split_once(":")
+            .ok_or(ParseError::at_end(line, ParseErrorKind::MissingDelimiter(":")))?;
+
+    let (winning_numbers, numbers_on_card) = parse_card_numbers(line, winning_numbers_and_numbers_on_card)?;
+
+    let winning_numbers = HashSet::from_iter(winning_numbers);
+    let numbers_on_card = BTreeSet::from_iter(numbers_on_card);
+
+    Ok(
+        ScratchCardGame { 
+            winning_numbers,
+            numbers_on_card
+        }
+    )
+}
+
+fn parse_card_numbers(line: &str, line_part: &str) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    let (winning_numbers, numbers_on_card) =
+        line_part
+            .split_once("|")
+            .ok_or(ParseError::at_end(line, ParseErrorKind::MissingDelimiter("|")))?;
+
+    let winning_numbers = parse_numbers(line, winning_numbers)?;
+    let numbers_on_card = parse_numbers(line, numbers_on_card)?;
+
+    Ok((winning_numbers, numbers_on_card))
+}
+
+fn parse_numbers(line: &str, line_part: &str) -> Result<Vec<u32>, ParseError> {
+    line_part
+        .split(" ")
+        // filter out "empty" parts caused by indentation of values
+        .filter( |str|
+            str
+                .chars()
+                .count() > 0
+        )
+        .map( |token|
+            token
+                .parse::<u32>()
+                .map_err(|_|
+                    ParseError::at(line, token, ParseErrorKind::InvalidNumber(token.to_string()))
+                )
+        )
+        .collect()
+}
+
+// generates `size` cards with ten winning numbers and 25 numbers on each card
+//
+// cards only win copies of cards within the same run of up to eleven cards,
+// which keeps the number of copies from growing exponentially with `size`
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let mut lines = vec![];
+    let mut run_end = 0;
+
+    for index in 0..size {
+        if index == run_end {
+            run_end = (index + rng.range(1..12) as usize).min(size);
+        }
+
+        let mut numbers: Vec<u32> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+
+        let winning_numbers = &numbers[..10];
+        let matches = rng.index((run_end - index - 1).min(10) + 1);
+
+        let mut numbers_on_card: Vec<u32> =
+            winning_numbers[..matches].iter()
+                .chain(numbers[10..35 - matches].iter())
+                .copied()
+                .collect();
+        rng.shuffle(&mut numbers_on_card);
+
+        lines.push(format!(
+            "Card {:>w$}: {} | {}",
+            index + 1,
+            format_numbers(winning_numbers, 2),
+            format_numbers(&numbers_on_card, 2),
+            w = size.to_string().len()));
+    }
+
+    lines
+}
+
+// cards with as many winning numbers, numbers and matches as before, but with other numbers
+//
+// the numbers are drawn from 1 to 99, or more if a card needs more distinct numbers
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    let mut anonymized = vec![];
+
+    for (index, line) in lines.iter().enumerate() {
+        let card = parse_game(line).map_err(|err| err.on_line(index+1))?;
+        let (card_id, _) = line.split_once(":").expect("the line was parsed");
+
+        let matches = card.get_number_of_winning_bets();
+        let winning_count = card.winning_numbers.len();
+        let distinct_count = winning_count + card.numbers_on_card.len() - matches;
+        let largest_number = distinct_count.max(99);
+
+        let mut numbers: Vec<u32> = (1..=largest_number as u32).collect();
+        rng.shuffle(&mut numbers);
+
+        let winning_numbers = &numbers[..winning_count];
+        let mut numbers_on_card: Vec<u32> =
+            winning_numbers[..matches].iter()
+                .chain(numbers[winning_count..distinct_count].iter())
+                .copied()
+                .collect();
+        rng.shuffle(&mut numbers_on_card);
+
+        let width = largest_number.to_string().len();
+        anonymized.push(format!("{}: {} | {}", card_id, format_numbers(winning_numbers, width), format_numbers(&numbers_on_card, width)));
+    }
+
+    Ok(anonymized)
+}
+
+fn format_numbers(numbers: &[u32], width: usize) -> String {
+    numbers.iter()
+        .map(|number| format!("{:>w$}", number, w = width))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod test {
+    mod parse_game {
+        use std::collections::{HashSet, BTreeSet};
+
+        use crate::day04::parse_game;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_should_parse_winning_numbers() {
+            assert_eq!(
+                HashSet::new(),
+
+                parse_game("Card 1: |").unwrap().winning_numbers,
+                "no winning numbers and no numbers on cards"
+            );
+
+            assert_eq!(
+                HashSet::from([42]),
+
+                parse_game("Card 1: 42 |").unwrap().winning_numbers,
+                "single winning number"
+            );
+
+            assert_eq!(
+                HashSet::from([1]),
+
+                parse_game("Card 1:  1 |").unwrap().winning_numbers,
+                "single winning number indented"
+            );
+
+            assert_eq!(
+                HashSet::from([1, 2, 10]),
+
+                parse_game("Card 1:  1  2  10 |").unwrap().winning_numbers,
+                "multiple winning numbers"
+            );
+        }
+
+        #[test]
+        fn it_should_parse_numbers_on_cards() {
+            assert_eq!(
+                BTreeSet::from([42]),
+
+                parse_game("Card 1: | 42").unwrap().numbers_on_card,
+                "single number on card"
+            );
+
+            assert_eq!(
+                BTreeSet::from([1]),
+
+                parse_game("Card 1: |  1").unwrap().numbers_on_card,
+                "single number on card indented"
+            );
+
+            assert_eq!(
+                BTreeSet::from([1, 2, 10]),
+
+                parse_game("Card 1: |  1  2  10").unwrap().numbers_on_card,
+                "multiple numbers on card"
+            );
+        }
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_input() {
+            assert_eq!(
+                Some(ParseError::new(1, 15, ParseErrorKind::InvalidNumber("4x".to_string()))),
+
+                parse_game("Card 1: 1 2 | 4x 5").err(),
+                "invalid number on card"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(1, 14, ParseErrorKind::MissingDelimiter("|"))),
+
+                parse_game("Card 1: 1 2 3").err(),
+                "missing separator between winning numbers and numbers on card"
+            );
+        }
+    }
+
+    mod anonymize {
+        use crate::Solution;
+        use crate::day04::{anonymize, Day04};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_keep_the_matches_of_each_card() {
+            let lines: Vec<String> = vec![
+                "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53".to_string(),
+                "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19".to_string(),
+                "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1".to_string()
+            ];
+
+            let anonymized = anonymize(&mut Rng::new(3), &lines).unwrap();
+
+            assert_ne!(lines, anonymized);
+            assert!(anonymized[1].starts_with("Card 2: "));
+
+            let matches = |lines: &[String]| Day04::parse(lines).unwrap().cards.iter().map(|card| card.get_number_of_winning_bets()).collect::<Vec<usize>>();
+            assert_eq!(vec![4, 2, 2], matches(&anonymized));
+        }
+    }
+}
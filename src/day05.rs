@@ -0,0 +1,816 @@
+use std::{fmt, io, ops};
+
+use crate::Solution;
+use crate::interval::IntervalSet;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::shell::{self, Command, Commands};
+use crate::trace;
+
+// the categories are the source of the first map followed by the destination of each map
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct MapChain {
+    pub maps: Vec<Map>,
+    pub categories: Vec<String>
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct Map {
+    pub ranges: Vec<MapRange>
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct MapRange {
+    pub source: u32,
+    pub destination: u32,
+    pub length: u32
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Range {
+    pub start: u32,
+    pub length: u32
+}
+
+pub struct Day05 {
+    pub seeds: Vec<u32>,
+    pub seed_to_location_map: MapChain
+}
+
+impl Solution for Day05 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let (seeds, seed_to_location_map) = process(lines)?;
+
+        Ok(Day05 { seeds, seed_to_location_map })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        self.seeds
+            .iter()
+            .map( |seed| {
+                if trace::enabled(1) {
+                    let path: Vec<String> = self.seed_to_location_map.path(*seed).iter().map(|value| value.to_string()).collect();
+                    crate::trace!(1, "seed {}", path.join(" -> "));
+                }
+
+                self.seed_to_location_map.index(*seed)
+            })
+            .min()
+            .map( |location| location.to_string() )
+            .ok_or(io::Error::other("no seeds"))
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "seeds must be given as pairs of start and length"));
+        }
+
+        // mapping the seeds forward is reversing the inverted chain
+        let location_to_seed_map = self.seed_to_location_map.invert();
+
+        let locations: IntervalSet<u64> =
+            self.seeds
+                .chunks(2)
+                .map( |pair| Range { start: pair[0], length: pair[1] } )
+                .flat_map( |seed_range| {
+                    let location_ranges = location_to_seed_map.reverse(seed_range.clone());
+                    crate::trace!(1, "seeds {} -> locations {}", seed_range, format_ranges(&location_ranges));
+
+                    location_ranges
+                })
+                .map( |location_range| location_range.to_interval() )
+                .collect();
+
+        locations
+            .min()
+            .map( |location| location.to_string() )
+            .ok_or(io::Error::other("no seeds"))
+    }
+}
+
+impl Commands for Day05 {
+    const COMMANDS: &'static [Command<Day05>] = &[
+        Command { name: "map", arguments: "<category> <value>", help: "follows the value through the maps", run: map_command },
+        Command { name: "reverse", arguments: "<category> <start>..<end>", help: "ranges of the first category that end up in the range", run: reverse_command }
+    ];
+}
+
+// "map seed 79" prints "seed 79 -> soil 81 -> ... -> location 82"
+fn map_command(day: &Day05, arguments: &[&str]) -> Result<String, String> {
+    let [category, value] = shell::arguments(arguments)?;
+    let chain = &day.seed_to_location_map;
+    let index = chain.category_index(category)?;
+    let value: u32 = shell::parse_argument(value, "value")?;
+
+    let mut value = value;
+    let mut path = vec![format!("{} {}", category, value)];
+    for (map, category) in chain.maps[index..].iter().zip(chain.categories[index+1..].iter()) {
+        value = map.index(value);
+        path.push(format!("{} {}", category, value));
+    }
+
+    Ok(path.join(" -> "))
+}
+
+// "reverse location 0..100" prints the ranges of the first category mapped into locations 0..100
+fn reverse_command(day: &Day05, arguments: &[&str]) -> Result<String, String> {
+    let [category, range] = shell::arguments(arguments)?;
+    let chain = &day.seed_to_location_map;
+    let index = chain.category_index(category)?;
+
+    let invalid_range = || format!("invalid range: '{}'", range);
+    let (start, end) = range.split_once("..").ok_or_else(invalid_range)?;
+    let (start, end): (u32, u64) = (shell::parse_argument(start, "start")?, shell::parse_argument(end, "end")?);
+    let length = end.checked_sub(start as u64).filter(|length| *length > 0).ok_or_else(invalid_range)?;
+    let range = Range { start, length: u32::try_from(length).map_err(|_| invalid_range())? };
+
+    let partial_chain = MapChain { maps: chain.maps[..index].to_vec(), categories: chain.categories[..=index].to_vec() };
+    let sources: IntervalSet<u64> = partial_chain.reverse(range.clone()).iter().map(Range::to_interval).collect();
+
+    let sources: Vec<String> = sources.iter().map(|interval| Range::from_interval(interval).to_string()).collect();
+    Ok(format!("{} {} <- {} {}", category, range, chain.categories[0], sources.join(" ")))
+}
+
+impl MapChain {
+    pub fn category_index(&self, category: &str) -> Result<usize, String> {
+        self.categories.iter()
+            .position(|known| known == category)
+            .ok_or(format!("unknown category: {}, expected one of {}", category, self.categories.join(", ")))
+    }
+
+    pub fn index(&self, index: u32) -> u32 {
+        let mut value = index;
+
+        self.maps
+            .iter()
+            .map( |map| { value = map.index(value); value } )
+            .last()
+            .unwrap_or(value)
+    }
+
+    // the index followed by its value after each map
+    pub fn path(&self, index: u32) -> Vec<u32> {
+        let mut path = vec![index];
+
+        for map in self.maps.iter() {
+            path.push(map.index(*path.last().unwrap()));
+        }
+
+        path
+    }
+
+    // chain mapping each destination back to its source
+    pub fn invert(&self) -> MapChain {
+        MapChain {
+            maps: self.maps.iter().rev().map(|map| map.invert()).collect(),
+            categories: self.categories.iter().rev().cloned().collect()
+        }
+    }
+
+    pub fn reverse(&self, range: Range) -> Vec<Range> {
+        let mut source_ranges = vec![range];
+
+        self.maps
+            .iter()
+            .rev()
+            .for_each(
+                |map| 
+                
+                {
+                    source_ranges = source_ranges
+                        .iter()
+                        .flat_map(|range| map.reverse(range))
+                        .collect();
+
+                    crate::trace!(2, "  {}", format_ranges(&source_ranges));
+                }
+            );
+
+        source_ranges
+    }
+}
+
+impl Map {
+    pub fn index(&self, index: u32) -> u32 {
+        self.ranges
+            .iter()
+            .find_map( |map_range| map_range.index(index) )
+            .unwrap_or(index)
+    }
+
+    pub fn invert(&self) -> Map {
+        let mut ranges: Vec<MapRange> =
+            self.ranges
+                .iter()
+                .map( |range| MapRange { source: range.destination, destination: range.source, length: range.length } )
+                .collect();
+        ranges.sort_by_key(|range| range.source);
+
+        Map { ranges }
+    }
+
+    pub fn reverse(&self, destination: &Range) -> Vec<Range> {
+        let mut decomposed_source_ranges = vec![destination.clone()];
+        
+        let mut ranges = self.ranges.clone();
+        ranges.sort_by_key( |range| range.destination );
+
+        ranges
+            .iter()
+            .filter( |range| range.get_destination_range().intersects_with(destination) )
+            .for_each(
+                    |range|
+
+                    {
+                        let index = decomposed_source_ranges.len()-1;
+                        let decomposition = decomposed_source_ranges[index].decomposition(&range.get_destination_range());
+                        decomposed_source_ranges.remove(index);
+
+                        for decomposed_range in decomposition.iter() {
+                            // reverse decomposed parts that match with destination, keep other decomposed parts as is
+                            decomposed_source_ranges.push(range.reverse(decomposed_range).unwrap_or(decomposed_range.clone()));
+                        }
+                    }
+                );
+
+        decomposed_source_ranges
+    }
+}
+
+impl MapRange {
+    // None for indexes outside of the source range
+    pub fn index(&self, index: u32) -> Option<u32> {
+        self.get_source_range().contains(index)
+            .then(|| self.destination + (index - self.source))
+    }
+
+    // None unless the range lies within the destination range
+    pub fn reverse(&self, range: &Range) -> Option<Range> {
+        if !self.get_destination_range().contains_range(range) {
+            return None;
+        }
+
+        // | ... source ... |
+        //         |
+        //         v
+        //   | ... dest   ... |
+        //
+        //    | range |
+        //    |
+        //    v
+        //  | src-r |
+
+
+        let range_offset = range.start - self.destination;
+
+        Some(Range { start: self.source + range_offset, length: range.length })
+    }
+
+    pub fn get_source_range(&self) -> Range {
+        Range { start: self.source, length: self.length }
+    }
+
+    pub fn get_destination_range(&self) -> Range {
+        Range { start: self.destination, length: self.length }
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let interval = self.to_interval();
+
+        write!(f, "{}..{}", interval.start, interval.end)
+    }
+}
+
+fn format_ranges(ranges: &[Range]) -> String {
+    ranges.iter().map(|range| range.to_string()).collect::<Vec<String>>().join(" ")
+}
+
+impl Range {
+    // half-open interval of the values, u64 so the end of ranges reaching u32::MAX fits
+    pub fn to_interval(&self) -> ops::Range<u64> {
+        self.start as u64..self.start as u64 + self.length as u64
+    }
+
+    // the interval must be within u32, as is any part of an interval from to_interval()
+    pub fn from_interval(interval: &ops::Range<u64>) -> Range {
+        Range { start: interval.start as u32, length: (interval.end - interval.start) as u32 }
+    }
+
+    pub fn contains(&self, value: u32) -> bool {
+        self.to_interval().contains(&(value as u64))
+    }
+
+    pub fn contains_range(&self, other: &Range) -> bool {
+        let (interval, other) = (self.to_interval(), other.to_interval());
+
+        other.start >= interval.start && other.end <= interval.end
+    }
+
+    pub fn decomposition(&self, other: &Range) -> Vec<Range> {
+        let mut decomposition = self.difference(other);
+        if let Some(intersection) = self.intersection(other) {
+            decomposition.push(intersection);
+        }
+        decomposition.sort_by_key(|range| range.start);
+
+        decomposition
+    }
+
+    pub fn difference(&self, to_substract: &Range) -> Vec<Range> {
+        IntervalSet::from(self.to_interval())
+            .difference(&IntervalSet::from(to_substract.to_interval()))
+            .iter()
+            .map(Range::from_interval)
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Range) -> Option<Range> {
+        IntervalSet::from(self.to_interval())
+            .intersection(&IntervalSet::from(other.to_interval()))
+            .iter()
+            .next()
+            .map(Range::from_interval)
+    }
+
+    pub fn intersects_with(&self, other: &Range) -> bool {
+        self.intersection(other).is_some()
+    }
+}
+
+pub fn process(lines: &[String]) -> Result<(Vec<u32>, MapChain), ParseError> {
+    let mut mapchain = 
+        MapChain {
+            maps: vec![],
+            categories: vec![]
+        };
+
+    // parse seeds
+    let mut lines = lines.iter().enumerate().map(|(index, line)| (index+1, line.as_str()));
+    let (line_number, seeds_line) = 
+        lines.next()
+            .ok_or(ParseError::new(1, 1, ParseErrorKind::Missing("seed list")))?;
+    let (_, seeds) = 
+        seeds_line.split_once(":")
+            .ok_or(ParseError::at_end(seeds_line, ParseErrorKind::MissingDelimiter(":")).on_line(line_number))?;
+    let seeds = 
+        seeds
+            .split_whitespace()
+            .map( |seed| parse_number(seeds_line, seed).map_err(|err| err.on_line(line_number)) )
+            .collect::<Result<Vec<u32>, ParseError>>()?;
+    let (line_number, empty_line) = 
+        lines.next()
+            .ok_or(ParseError::new(line_number+1, 1, ParseErrorKind::Missing("empty line after seed list")))?;
+    if !empty_line.is_empty() {
+        return Err(ParseError::new(line_number, 1, ParseErrorKind::Unexpected { expected: "empty line after seed list", found: empty_line.to_string() }));
+    }
+
+    // parse maps
+    while let Some(map) = process_map(&mut lines, &mut mapchain.categories)? {
+        mapchain.maps.push(map);
+    }
+
+    Ok((seeds, mapchain))
+}
+
+fn parse_number(line: &str, token: &str) -> Result<u32, ParseError> {
+    token.parse::<u32>()
+        .map_err(|_| ParseError::at(line, token, ParseErrorKind::InvalidNumber(token.to_string())))
+}
+
+// adds the source category of the first map and the destination category of each map to the categories
+fn process_map<'a>(lines: &mut impl Iterator<Item = (usize, &'a str)>, categories: &mut Vec<String>) -> Result<Option<Map>, ParseError> {
+    let Some((line_number, header)) = lines.next() else {
+        return Ok(None);
+    };
+
+    let (source, destination) =
+        header.strip_suffix(" map:")
+            .and_then(|categories| categories.split_once("-to-"))
+            .ok_or(ParseError::new(line_number, 1, ParseErrorKind::Unexpected { expected: "'<source>-to-<destination> map:'", found: header.to_string() }))?;
+
+    let mut map =
+        Map {
+            ranges: vec![]
+        };
+
+    for (line_number, line) in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let mut items = line.split(" ");
+        let destination = items.next().unwrap_or_default();
+        let source = items.next().ok_or(ParseError::at_end(line, ParseErrorKind::Missing("source")).on_line(line_number))?;
+        let length = items.next().ok_or(ParseError::at_end(line, ParseErrorKind::Missing("length")).on_line(line_number))?;
+
+        let destination = parse_number(line, destination).map_err(|err| err.on_line(line_number))?;
+        let source = parse_number(line, source).map_err(|err| err.on_line(line_number))?;
+        let length = parse_number(line, length).map_err(|err| err.on_line(line_number))?;
+
+        map.ranges.push(
+            MapRange { 
+                source, 
+                destination, 
+                length
+            }
+        );
+    }
+
+    // only the end of the input ends the chain
+    if map.ranges.is_empty() {
+        return Err(ParseError::at_end(header, ParseErrorKind::Missing("ranges of the map")).on_line(line_number));
+    }
+
+    map.ranges.sort_by_key(|range| range.source);
+
+    if categories.is_empty() {
+        categories.push(source.to_string());
+    }
+    categories.push(destination.to_string());
+
+    Ok(Some(map))
+}
+
+// generates an almanac with ten seed ranges and up to `size` ranges per map
+//
+// each map shuffles the blocks of a random partition of 0..4e9, some of the
+// blocks, never all of them, are dropped to leave gaps that map to themselves
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    const LIMIT: u64 = 4_000_000_000;
+    const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+    let seeds: Vec<String> =
+        (0..10)
+            .flat_map(|_| {
+                let start = rng.range(0..LIMIT);
+                let length = rng.range(1..(LIMIT - start).min(100_000_000) + 1);
+
+                [start.to_string(), length.to_string()]
+            })
+            .collect();
+
+    let mut lines = vec![format!("seeds: {}", seeds.join(" "))];
+
+    for categories in CATEGORIES.windows(2) {
+        let mut bounds: Vec<u64> = (1..size.max(1)).map(|_| rng.range(1..LIMIT)).collect();
+        bounds.extend([0, LIMIT]);
+        bounds.sort();
+        bounds.dedup();
+
+        // source blocks in random order, their destinations follow each other
+        let mut blocks: Vec<(u64, u64)> = bounds.windows(2).map(|bound| (bound[0], bound[1] - bound[0])).collect();
+        rng.shuffle(&mut blocks);
+
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:", categories[0], categories[1]));
+
+        let mut destination = 0;
+        for (index, (source, length)) in blocks.into_iter().enumerate() {
+            if index == 0 || !rng.chance(10) {
+                lines.push(format!("{} {} {}", destination, source, length));
+            }
+
+            destination += length;
+        }
+    }
+
+    lines
+}
+
+// the same maps scaled and shifted, with other seed ranges and the ranges of each map in a random order
+//
+// every value x becomes k * x + offset and every length k * length, which maps the
+// gaps between the ranges to themselves as before
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    let (seeds, chain) = process(lines)?;
+
+    let seed_ends = seeds.chunks(2).map(|pair| pair.iter().map(|value| *value as u64).sum::<u64>());
+    let range_ends =
+        chain.maps.iter()
+            .flat_map(|map| map.ranges.iter())
+            .map(|range| range.source.max(range.destination) as u64 + range.length as u64);
+    // values beyond u32::MAX are left as they are, k is 1 and the offset 0 then
+    let largest_value = seed_ends.chain(range_ends).max().unwrap_or(0).clamp(1, u32::MAX as u64);
+
+    let k = rng.range(1..(u32::MAX as u64 / largest_value).clamp(1, 4) + 1);
+    let offset = rng.range(0..(u32::MAX as u64).saturating_sub(k * largest_value) + 1);
+    let value = |x: u32| (k * x as u64 + offset).to_string();
+    let length = |length: u32| (k * length as u64).to_string();
+
+    // other seed ranges, not empty and no longer than before, as the maps may leave no room to shift them
+    let mut seed_pairs: Vec<String> =
+        seeds.chunks(2)
+            .map(|pair| match pair {
+                [_, range_length] => {
+                    let range_length = rng.range(1..(*range_length).max(1) as u64 + 1);
+                    let start = rng.range(0..largest_value - range_length + 1);
+                    format!("{} {}", value(start as u32), length(range_length as u32))
+                }
+                [_] => value(rng.range(0..largest_value) as u32),
+                _ => unreachable!("chunks of two")
+            })
+            .collect();
+    rng.shuffle(&mut seed_pairs);
+
+    let mut anonymized = vec![format!("seeds: {}", seed_pairs.join(" "))];
+
+    for (map, categories) in chain.maps.iter().zip(chain.categories.windows(2)) {
+        let mut ranges: Vec<String> =
+            map.ranges.iter()
+                .map(|range| format!("{} {} {}", value(range.destination), value(range.source), length(range.length)))
+                .collect();
+        rng.shuffle(&mut ranges);
+
+        anonymized.push(String::new());
+        anonymized.push(format!("{}-to-{} map:", categories[0], categories[1]));
+        anonymized.extend(ranges);
+    }
+
+    Ok(anonymized)
+}
+
+#[cfg(test)]
+mod test {
+    mod process {
+        use crate::Solution;
+        use crate::day05::{process, Day05};
+        use crate::parse_error::{ParseError, ParseErrorKind};
+        use crate::shell::Shell;
+        use crate::trace;
+
+        const SAMPLE_INPUT: &str = 
+"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+        #[test]
+        fn it_should_map_sample_seed_13_to_location_35() {
+            let sample_input_lines: Vec<String> = 
+                SAMPLE_INPUT
+                .split("\n")
+                .map( |str| str.to_string() )
+                .collect();
+
+            let (_, seed_to_location_map) = process(&sample_input_lines).unwrap();
+            assert_eq!(
+                35,
+                seed_to_location_map.index(13),
+                "sample input"
+            )
+        }
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_map_ranges() {
+            let lines: Vec<String> = 
+                SAMPLE_INPUT
+                .replace("52 50 48", "52 50 4x")
+                .split("\n")
+                .map( |str| str.to_string() )
+                .collect();
+
+            assert_eq!(
+                Some(ParseError::new(5, 7, ParseErrorKind::InvalidNumber("4x".to_string()))),
+
+                process(&lines).err()
+            );
+        }
+
+        #[test]
+        fn it_should_report_maps_without_ranges() {
+            let lines: Vec<String> =
+                SAMPLE_INPUT
+                .replace("0 15 37\n37 52 2\n39 0 15\n", "")
+                .split("\n")
+                .map( |str| str.to_string() )
+                .collect();
+
+            assert_eq!(
+                Some(ParseError::new(7, 24, ParseErrorKind::Missing("ranges of the map"))),
+
+                process(&lines).err()
+            );
+
+            let last_map_without_ranges: Vec<String> = ["seeds: 79", "", "seed-to-soil map:"].map(String::from).to_vec();
+            assert_eq!(
+                Some(ParseError::new(3, 18, ParseErrorKind::Missing("ranges of the map"))),
+
+                process(&last_map_without_ranges).err()
+            );
+        }
+
+        #[test]
+        fn it_should_trace_how_the_seeds_flow_through_the_maps() {
+            let lines: Vec<String> = SAMPLE_INPUT.split("\n").map( |str| str.to_string() ).collect();
+            let day = Day05::parse(&lines).unwrap();
+
+            let (_, part1_lines) = trace::capture(1, || day.part1().unwrap());
+            assert_eq!(
+                vec![
+                    "seed 79 -> 81 -> 81 -> 81 -> 74 -> 78 -> 78 -> 82",
+                    "seed 14 -> 14 -> 53 -> 49 -> 42 -> 42 -> 43 -> 43",
+                    "seed 55 -> 57 -> 57 -> 53 -> 46 -> 82 -> 82 -> 86",
+                    "seed 13 -> 13 -> 52 -> 41 -> 34 -> 34 -> 35 -> 35"
+                ],
+                part1_lines
+            );
+
+            let (_, part2_lines) = trace::capture(1, || day.part2().unwrap());
+            assert_eq!(2, part2_lines.len());
+            assert!(part2_lines[1].starts_with("seeds 55..68 -> locations "), "{}", part2_lines[1]);
+            assert!(part2_lines.iter().any(|line| line.contains(" 46..")), "lowest location 46");
+        }
+
+        #[test]
+        fn it_should_map_values_and_reverse_ranges_in_the_shell() {
+            let lines: Vec<String> = SAMPLE_INPUT.split("\n").map( |str| str.to_string() ).collect();
+            let day = Day05::parse(&lines).unwrap();
+
+            assert_eq!(
+                Ok("seed 79 -> soil 81 -> fertilizer 81 -> water 81 -> light 74 -> temperature 78 -> humidity 78 -> location 82".to_string()),
+                day.execute("map seed 79")
+            );
+            assert_eq!(Ok("humidity 78 -> location 82".to_string()), day.execute("map humidity 78"));
+            assert_eq!(Ok("location 46..47 <- seed 82..83".to_string()), day.execute("reverse location 46..47"));
+            assert_eq!(Ok("soil 81..83 <- seed 79..81".to_string()), day.execute("reverse soil 81..83"));
+
+            assert!(day.execute("map dirt 3").unwrap_err().starts_with("unknown category: dirt"));
+            assert!(day.execute("reverse location 9..9").unwrap_err().starts_with("invalid range: '9..9'"));
+        }
+    }
+
+    mod map_reverse {
+        use crate::day05::{Map, MapRange, Range};
+
+        #[test]
+        fn it_should_reverse_a_destination_range_through_a_single_map_range() {
+            let map = Map {
+                ranges: vec![
+                    MapRange { source: 0, destination: 3, length: 1 }
+                ]
+            };
+
+            assert_eq!(
+                vec![ Range{ start: 0, length: 1} ],
+
+                map.reverse(&Range { start: 3, length: 1 }),
+                "reverses a range that equals to the destination range of a defined maprange"
+            );
+
+            assert_eq!(
+                vec![ Range{ start: 0, length: 2} ],
+
+                map.reverse(&Range { start: 0, length: 2 }),
+                "keeps a range constant that does not overlap with any defined ranges in the map"
+            );
+
+            assert_eq!(
+                vec![ Range{ start: 2, length: 1}, Range { start: 0, length: 1 } ],
+
+                map.reverse(&Range { start: 2, length: 2 }),
+                "splits the destination range and reverses the overlapping part"
+            );
+        }
+
+        #[test]
+        fn it_should_reverse_a_destination_range_through_a_multiple_map_ranges() {
+            let map = Map {
+                ranges: vec![
+                    MapRange { source: 0, destination: 12, length: 1 },
+                    MapRange { source: 5, destination: 15, length: 2 }
+                ]
+            };
+
+            assert_eq!(
+                vec![ 
+                    Range { start: 10, length: 2 },
+                    Range { start: 0, length: 1 },
+                    Range { start: 13, length: 2 },
+                    Range { start: 5, length: 2 },
+                    Range { start: 17, length: 3 } ],
+
+                map.reverse(&Range { start: 10, length: 10 }),
+                "splits the destination range and reverses the overlapping parts"
+            );
+        }
+    }
+
+    mod map_range {
+        use crate::day05::{MapRange, Range};
+
+        #[test]
+        fn it_should_only_map_indexes_of_the_source_range() {
+            let map_range = MapRange { source: 98, destination: 50, length: 2 };
+
+            assert_eq!(Some(50), map_range.index(98));
+            assert_eq!(Some(51), map_range.index(99));
+            assert_eq!(None, map_range.index(97));
+            assert_eq!(None, map_range.index(100));
+        }
+
+        #[test]
+        fn it_should_only_reverse_ranges_within_the_destination_range() {
+            let map_range = MapRange { source: 50, destination: 52, length: 48 };
+
+            assert_eq!(Some(Range { start: 53, length: 2 }), map_range.reverse(&Range { start: 55, length: 2 }));
+            assert_eq!(None, map_range.reverse(&Range { start: 51, length: 2 }));
+            assert_eq!(None, map_range.reverse(&Range { start: 99, length: 2 }));
+        }
+    }
+
+    mod generate {
+        use crate::Solution;
+        use crate::day05::{generate, Day05};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_keep_a_range_in_every_map() {
+            for seed in 0..50 {
+                let lines = generate(&mut Rng::new(seed), 1);
+
+                assert!(Day05::parse(&lines).is_ok(), "{}", lines.join("\n"));
+            }
+        }
+    }
+
+    mod anonymize {
+        use crate::Solution;
+        use crate::day05::{anonymize, generate, Day05};
+        use crate::random::Rng;
+
+        fn lines(input: &str) -> Vec<String> {
+            input.split("\n").map(|e| e.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_keep_generated_inputs_solvable() {
+            for seed in 0..10 {
+                let anonymized = anonymize(&mut Rng::new(seed), &generate(&mut Rng::new(seed), 10)).unwrap();
+                let day = Day05::parse(&anonymized).unwrap();
+
+                assert!(day.part1().is_ok(), "{}", anonymized.join("\n"));
+                assert!(day.part2().is_ok(), "{}", anonymized.join("\n"));
+            }
+        }
+
+        #[test]
+        fn it_should_never_empty_a_seed_range() {
+            let input = lines("seeds: 79 1\n\nseed-to-soil map:\n50 98 2");
+
+            for seed in 0..20 {
+                let anonymized = anonymize(&mut Rng::new(seed), &input).unwrap();
+                let seeds: Vec<&str> = anonymized[0].split_whitespace().collect();
+
+                assert_ne!("0", seeds[2], "{}", anonymized[0]);
+                assert!(Day05::parse(&anonymized).unwrap().part2().is_ok());
+            }
+        }
+
+        #[test]
+        fn it_should_keep_values_beyond_the_largest_u32() {
+            let input = lines("seeds: 4294967000 200\n\nseed-to-soil map:\n4294967000 0 1000");
+
+            for seed in 0..5 {
+                let anonymized = anonymize(&mut Rng::new(seed), &input).unwrap();
+
+                assert!(Day05::parse(&anonymized).unwrap().part2().is_ok(), "{}", anonymized.join("\n"));
+            }
+        }
+    }
+}
\ No newline at end of file
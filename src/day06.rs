@@ -0,0 +1,254 @@
+use std::{io, ops};
+
+use crate::Solution;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+
+pub struct Day06 {
+    pub scoreboard: Vec<ScoreboardRecord>,
+    pub scoreboard_record: ScoreboardRecord
+}
+
+impl Solution for Day06 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        if lines.len() < 2 {
+            return Err(ParseError::new(lines.len()+1, 1, ParseErrorKind::Missing(if lines.is_empty() { "time line" } else { "distance line" })));
+        }
+
+        Ok(Day06 {
+            scoreboard: parse_times_and_records_part1(&lines[0], &lines[1])?,
+            scoreboard_record: parse_time_and_record_part2(&lines[0], &lines[1])?
+        })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        Ok(
+            self.scoreboard
+                .iter()
+                .map(
+                    |record|
+
+                    get_winning_acceleration_ms(record.time_ms, record.distance_record_mm)
+                    .map(|range| range.end()-range.start()+1)
+                    .unwrap_or_default()
+                 )
+                 .product::<u64>()
+                 .to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        Ok(
+            get_winning_acceleration_ms(self.scoreboard_record.time_ms, self.scoreboard_record.distance_record_mm)
+                .map(|range| range.end()-range.start()+1)
+                .unwrap_or_default()
+                .to_string())
+    }
+}
+
+#[derive(Debug)]
+pub struct ScoreboardRecord {
+    pub time_ms: u64,
+    pub distance_record_mm: u64
+}
+
+pub fn parse_times_and_records_part1(line_time: &str, line_distance_record: &str) -> Result<Vec<ScoreboardRecord>, ParseError> {
+    let times_ms: Vec<u64> = 
+        parse_values(line_time, "Time:").map_err(|err| err.on_line(1))?
+            .iter()
+            .map( |value| value.parse::<u64>().unwrap() )
+            .collect();
+
+    let distance_record_mm: Vec<u64> = 
+        parse_values(line_distance_record, "Distance:").map_err(|err| err.on_line(2))?
+            .iter()
+            .map( |value| value.parse::<u64>().unwrap() )
+            .collect();
+
+    if times_ms.len() != distance_record_mm.len() {
+        return Err(ParseError::at_end(line_distance_record, ParseErrorKind::InvalidLength { expected: times_ms.len(), found: distance_record_mm.len() }).on_line(2));
+    }
+
+    Ok(
+        times_ms.iter()
+            .zip( distance_record_mm.iter() )
+            .map( |pair| ScoreboardRecord { time_ms: *pair.0, distance_record_mm: *pair.1 } )
+            .collect())
+}
+
+pub fn parse_time_and_record_part2(line_time: &str, line_distance_record: &str) -> Result<ScoreboardRecord, ParseError> {
+    let time_ms: u64 = parse_concatenated_value(line_time, "Time:").map_err(|err| err.on_line(1))?;
+    let distance_record_mm: u64 = parse_concatenated_value(line_distance_record, "Distance:").map_err(|err| err.on_line(2))?;
+
+    Ok(ScoreboardRecord {
+        time_ms,
+        distance_record_mm
+    })
+}
+
+// returns the whitespace separated values following the label, each of them is a valid u64
+fn parse_values<'a>(line: &'a str, label: &'static str) -> Result<Vec<&'a str>, ParseError> {
+    let values = 
+        line.strip_prefix(label)
+            .ok_or(ParseError::at(line, line, ParseErrorKind::Unexpected { expected: label, found: line.split_whitespace().next().unwrap_or_default().to_string() }))?;
+
+    values.split_whitespace()
+        .map(|value|
+            match value.parse::<u64>() {
+                Ok(_) => Ok(value),
+                Err(_) => Err(ParseError::at(line, value, ParseErrorKind::InvalidNumber(value.to_string())))
+            })
+        .collect()
+}
+
+fn parse_concatenated_value(line: &str, label: &'static str) -> Result<u64, ParseError> {
+    let values = parse_values(line, label)?;
+    let first_value = values.first().ok_or(ParseError::at_end(line, ParseErrorKind::Missing("value")))?;
+
+    let concatenated_value = values.concat();
+    concatenated_value.parse()
+        .map_err(|_| ParseError::at(line, first_value, ParseErrorKind::InvalidNumber(concatenated_value)))
+}
+
+pub fn get_winning_acceleration_ms(time_ms: u64, distance_record_mm: u64) -> Option<ops::RangeInclusive<u64>> {
+    let mut acceleration_time = 0;
+
+    while acceleration_time < time_ms/2 && acceleration_time*(time_ms - acceleration_time) <= distance_record_mm{
+        acceleration_time += 1;
+    }
+
+    if acceleration_time*(time_ms - acceleration_time) > distance_record_mm {
+        Some(acceleration_time..=time_ms-acceleration_time)
+    } else {
+        None
+    }
+}
+
+// generates `size` races, at most four, so the concatenated time and record of part 2 fit into u64
+//
+// every record, including the concatenated one, can be beaten
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let races = size.clamp(1, 4);
+
+    loop {
+        let times: Vec<u64> = (0..races).map(|_| rng.range(7..100)).collect();
+        let records: Vec<u64> = times.iter().map(|time| rng.range(0..(time / 2) * (time - time / 2))).collect();
+
+        let concatenate = |values: &[u64]| values.iter().map(|value| value.to_string()).collect::<String>().parse::<u128>().unwrap();
+        let time = concatenate(&times);
+
+        if concatenate(&records) < (time / 2) * (time - time / 2) {
+            return vec![format_values("Time:", &times), format_values("Distance:", &records)];
+        }
+    }
+}
+
+// as many races with times of as many digits as before, but with other times and records
+//
+// like in generate() every record, including the concatenated one, can be beaten
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    let races = Day06::parse(lines)?.scoreboard;
+
+    loop {
+        let times: Vec<u64> =
+            races.iter()
+                .map(|race| {
+                    let digits = race.time_ms.to_string().len() as u32;
+                    rng.range(10u64.pow(digits - 1).max(2)..10u64.pow(digits).max(3))
+                })
+                .collect();
+        let records: Vec<u64> = times.iter().map(|time| rng.range(0..(time / 2) * (time - time / 2))).collect();
+
+        let anonymized = vec![format_values("Time:", &times), format_values("Distance:", &records)];
+
+        let Ok(race) = parse_time_and_record_part2(&anonymized[0], &anonymized[1]) else { continue };
+        if (race.distance_record_mm as u128) < (race.time_ms as u128 / 2) * (race.time_ms as u128 - race.time_ms as u128 / 2) {
+            return Ok(anonymized);
+        }
+    }
+}
+
+fn format_values(label: &str, values: &[u64]) -> String {
+    format!("{:<9}{}", label, values.iter().map(|value| format!("{:>5}", value)).collect::<String>())
+}
+
+#[cfg(test)]
+mod test {
+    mod get_winning_ms {
+        use crate::day06::get_winning_acceleration_ms;
+
+        #[test]
+        fn it_should_return_ranges_of_ms_that_win_the_race() {
+            // boats initial speed is 0 mm / ms
+            // each ms the button gets hold the speed increases by 1 mm / ms
+            // after releasing the button the speed is kept
+
+            // so holding the button for 3 ms increases the speed to 1 mm / ms, to 2 mm / ms, to 3 mm / ms
+            // the boat does not travel the first 3 ms
+            // in the remaining ms it will travel with 3 mm / ms until the given time is up
+
+            assert_eq!(
+                Some(2..=5),
+
+                get_winning_acceleration_ms(7, 9),
+                "travelling for 5 ms with 2 mm / ms will beat the distance record of 9 mm, 
+                 also travelling for 2 ms with 5 mm / ms will beat the distance record"
+            );
+
+            assert_eq!(
+                Some(4..=11),
+
+                get_winning_acceleration_ms(15, 40),
+                "travelling for 11 ms with 4 mm / ms will beat the distance record of 40 mm, 
+                 also travelling for 4 ms with 11 mm / ms will beat the distance record"
+            );
+
+            assert_eq!(
+                Some(11..=19),
+
+                get_winning_acceleration_ms(30, 200),
+                "travelling for 19 ms with 11 mm / ms will beat the distance record of 200 mm, 
+                 also travelling for 11 ms with 19 mm / ms will beat the distance record"
+            );
+        }
+
+        #[test]
+        fn it_should_return_none_for_distance_record_that_is_impossible_to_beat() {
+            assert_eq!(
+                None,
+
+                get_winning_acceleration_ms(7, 12),
+                "travelling for 4 ms with 3 mm / ms will get at most 12 mm far"
+            );
+        }
+    }
+
+    mod parse {
+        use crate::Solution;
+        use crate::day06::Day06;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_input() {
+            assert_eq!(
+                Some(ParseError::new(2, 15, ParseErrorKind::InvalidNumber("4O".to_string()))),
+
+                Day06::parse(&["Time:      7  15   30".to_string(), "Distance:  9  4O  200".to_string()]).err(),
+                "invalid distance"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(2, 17, ParseErrorKind::InvalidLength { expected: 3, found: 2 })),
+
+                Day06::parse(&["Time:      7  15   30".to_string(), "Distance:  9  40".to_string()]).err(),
+                "missing distance"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(1, 1, ParseErrorKind::Unexpected { expected: "Time:", found: "Duration:".to_string() })),
+
+                Day06::parse(&["Duration:  7  15   30".to_string(), "Distance:  9  40  200".to_string()]).err(),
+                "unknown label"
+            );
+        }
+    }
+}
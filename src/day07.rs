@@ -0,0 +1,785 @@
+use std::{cmp::{self, Ordering}, io, fmt::Display, str::FromStr};
+
+use crate::Solution;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::shell::{self, Command, Commands};
+use crate::trace;
+
+pub struct Day07 {
+    pub hands: Vec<HandAndBid>
+}
+
+impl Solution for Day07 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        Ok(Day07 { hands: parse_lines(lines)? })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        let ranked_hands = process_lines(&self.hands);
+        trace_ranking(&ranked_hands, Hand::get_win_type);
+
+        Ok(total_winnings(&ranked_hands).to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        let ranked_hands = process_lines_part2(&self.hands);
+        trace_ranking(&ranked_hands, Hand::get_win_type_part2);
+
+        Ok(total_winnings(&ranked_hands).to_string())
+    }
+}
+
+impl Commands for Day07 {
+    const COMMANDS: &'static [Command<Day07>] = &[
+        Command { name: "rank", arguments: "<hand>", help: "rank of the hand among the hands of the input", run: rank_command }
+    ];
+}
+
+// "rank KK677" prints the rank of the hand with the rules of both parts
+fn rank_command(day: &Day07, arguments: &[&str]) -> Result<String, String> {
+    let [hand] = shell::arguments(arguments)?;
+    let hand = hand.parse::<Hand>().map_err(|err| err.to_string())?;
+
+    Ok(format!(
+        "part 1: {}\npart 2: {}",
+        describe_rank(&day.hands, &hand, process_lines, Hand::get_win_type),
+        describe_rank(&day.hands, &hand, process_lines_part2, Hand::get_win_type_part2)))
+}
+
+fn describe_rank(hands: &[HandAndBid], hand: &Hand, rank: fn(&[HandAndBid]) -> Vec<HandAndBid>, win_type: fn(&Hand) -> WinType) -> String {
+    if hands.iter().any(|e| e.hand == *hand) {
+        let ranked_hands = rank(hands);
+        let pos = ranked_hands.iter().position(|e| e.hand == *hand).unwrap();
+        let bid = ranked_hands[pos].bid;
+
+        format!("rank {} of {}, {:?}, bid {} wins {}", pos+1, ranked_hands.len(), win_type(hand), bid, (pos+1) as u32 * bid)
+    } else {
+        let mut hands = hands.to_vec();
+        hands.push(HandAndBid { hand: hand.clone(), bid: 0 });
+        let ranked_hands = rank(&hands);
+        let pos = ranked_hands.iter().position(|e| e.hand == *hand).unwrap();
+
+        format!("not in the input, would rank {} of {}, {:?}", pos+1, ranked_hands.len(), win_type(hand))
+    }
+}
+
+fn trace_ranking(ranked_hands: &[HandAndBid], win_type: fn(&Hand) -> WinType) {
+    if trace::enabled(1) {
+        for (pos, e) in ranked_hands.iter().enumerate() {
+            crate::trace!(1, "rank {}: {} {:?}, bid {} wins {}", pos+1, e.hand, win_type(&e.hand), e.bid, (pos+1) as u32 * e.bid);
+        }
+    }
+}
+
+pub fn total_winnings(ranked_hands: &[HandAndBid]) -> u32 {
+    ranked_hands
+        .iter()
+        .enumerate()
+        .map( |(pos, e)| (pos+1) as u32 * e.bid )
+        .sum::<u32>()
+}
+
+pub fn parse_lines(lines: &[String]) -> Result<Vec<HandAndBid>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map( |(index, line)| line.parse::<HandAndBid>().map_err(|err| err.on_line(index+1)) )
+        .collect()
+}
+
+pub fn process_lines(hands: &[HandAndBid]) -> Vec<HandAndBid> {
+    let mut hands = hands.to_vec();
+
+    hands.sort_by_key(|e| e.hand.clone());  // sort by first card, second card, ...
+    hands.sort_by_key(                           // sort by win-type - keeping the order for hands with identical win-type intact
+        |e| e.hand.get_win_type() 
+    );
+
+    hands
+}
+
+pub fn process_lines_part2(hands: &[HandAndBid]) -> Vec<HandAndBid> {
+    let mut hands = hands.to_vec();
+
+    let card_comparator_part2 = 
+        |e1: &Card, e2: &Card|
+        {
+            match (e1, e2) {
+                (Card::Jack, Card::Jack) => Ordering::Equal,
+                (Card::Jack, _) => Ordering::Less,
+                (_, Card::Jack) => Ordering::Greater,
+                _ => e1.partial_cmp(e2).unwrap()
+            }
+        };
+    let hand_comparator_part2 = 
+        |e1: &Hand, e2: &Hand|
+        {
+            let ordering_by_element =
+                e1.cards.iter()
+                    .zip(e2.cards.iter())
+                    .map(|(e1, e2)| card_comparator_part2(e1, e2));
+
+            for ordering in ordering_by_element {
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+
+            Ordering::Equal
+        };
+
+    let hand_and_bit_comparator_part2 =
+        |e1: &HandAndBid, e2: &HandAndBid| hand_comparator_part2(&e1.hand, &e2.hand);
+
+    hands.sort_by(hand_and_bit_comparator_part2);   // sort by first card, second card, ...
+    hands.sort_by_key(                              // sort by win-type - keeping the order for hands with identical win-type intact
+        |e| e.hand.get_win_type_part2() 
+    );
+
+    hands
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct HandAndBid {
+    pub hand: Hand,
+    pub bid: u32
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq, cmp::Eq, PartialOrd, cmp::Ord)]
+pub struct Hand {
+    pub cards: [Card; 5]
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq, cmp::Eq, PartialOrd, cmp::Ord)]
+pub enum Card {
+    Ace=14,
+    King=13,
+    Queen=12,
+    Jack=11,
+    Ten=10,
+    Nine=9,
+    Eight=8,
+    Seven=7,
+    Six=6,
+    Five=5,
+    Four=4,
+    Three=3,
+    Two=2
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq, cmp::Eq, PartialOrd, cmp::Ord)]
+pub enum WinType {
+    FiveOfAKind=7,
+    FourOfAKind=6,
+    FullHouse=5,
+    ThreeOfAKind=4,
+    TwoPair=3,
+    OnePair=2,
+    HighCard=1
+}
+
+impl FromStr for HandAndBid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hand, bid) = 
+            s.split_once(" ")
+            .ok_or(ParseError::at_end(s, ParseErrorKind::MissingDelimiter(" ")) )?;
+
+        // the hand starts at the beginning of the line, so its columns are valid for the whole line
+        let hand = hand.parse::<Hand>()?;
+        let bid = 
+            bid.parse::<u32>()
+            .or( Err(ParseError::at(s, bid, ParseErrorKind::InvalidNumber(bid.to_string()))) )?;
+
+        Ok(HandAndBid {
+            hand,
+            bid
+        })
+    }
+}
+
+impl Hand {
+    // None unless there are exactly 5 cards
+    pub fn new(cards: Vec<Card>) -> Option<Hand> {
+        Some(Hand {
+            cards: cards.try_into().ok()?
+        })
+    }
+
+    pub fn get_win_type(&self) -> WinType {
+        // count of 2's, 3's, ..., A's
+        let mut card_count = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 ,0 ,0];
+
+        // count of single, pairs, triples, ...
+        let mut set_of_size_count = [0, 0, 0, 0, 0];
+        
+        self.cards.iter().cloned().for_each(
+            |card| card_count[card as usize - 2] += 1 );
+        card_count.iter()
+            .for_each( |card_count| if *card_count > 0 { set_of_size_count[*card_count-1] += 1 } );
+
+        match set_of_size_count {
+            [_, _, _, _, 1] => WinType::FiveOfAKind,
+            [_,_,_,1,_] => WinType::FourOfAKind,
+            [_,1,1,_,_] => WinType::FullHouse,
+            [_,0,1,_,_] => WinType::ThreeOfAKind,
+            [_,2,_,_,_] => WinType::TwoPair,
+            [_,1,0,_,_] => WinType::OnePair,
+            _ => WinType::HighCard
+        }
+    }
+
+    pub fn get_win_type_part2(&self) -> WinType {
+        // count of 2's, 3's, ..., A's
+        let mut card_count = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 ,0 ,0];
+
+        // count of single, pairs, triples, ...
+        let mut set_of_size_count = [0, 0, 0, 0, 0];
+        
+        self.cards.iter().cloned().for_each(
+            |card| card_count[card as usize - 2] += 1 );
+        card_count.iter()
+            .for_each( |card_count| if *card_count > 0 { set_of_size_count[*card_count-1] += 1 } );
+
+        let jack_count = card_count[Card::Jack as usize - 2];
+        if jack_count > 0 && jack_count < 5 {
+            // apply joker count to the highest set
+            let highest_set = 
+                set_of_size_count
+                    .iter()
+                    .enumerate()
+                    .rposition(|(pos, e)| if pos == jack_count-1 { *e > 1 } else { *e > 0 } )
+                    .unwrap();
+
+            // jacks are used to ...
+            set_of_size_count[jack_count-1] -= 1;
+
+            // converted the highest set into a even higher one
+            set_of_size_count[highest_set] -= 1;
+            set_of_size_count[highest_set + jack_count] += 1;
+        }
+
+        match set_of_size_count {
+            [_, _, _, _, 1] => WinType::FiveOfAKind,
+            [_,_,_,1,_] => WinType::FourOfAKind,
+            [_,1,1,_,_] => WinType::FullHouse,
+            [_,0,1,_,_] => WinType::ThreeOfAKind,
+            [_,2,_,_,_] => WinType::TwoPair,
+            [_,1,0,_,_] => WinType::OnePair,
+            _ => WinType::HighCard
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards =
+            s.char_indices()
+                .map( |(index, char)| {
+                    format!("{}", char)
+                        .parse::<Card>()
+                        .map_err(|err| ParseError::at(s, &s[index..], err.kind))
+                })
+                .collect::<Result<Vec<Card>, ParseError>>()?;
+
+        let found = cards.len();
+
+        Hand::new(cards).ok_or(ParseError::at(s, s, ParseErrorKind::InvalidLength { expected: 5, found }))
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for card in self.cards.iter() {
+            write!(f, "{}", card)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Card::Ace => { f.write_str("A")?; }
+            Card::King => { f.write_str("K")?; }
+            Card::Queen => { f.write_str("Q")?; }
+            Card::Jack => { f.write_str("J")?; }
+            Card::Ten => { f.write_str("T")?; }
+            Card::Nine => { f.write_str("9")?; }
+            Card::Eight => { f.write_str("8")?; }
+            Card::Seven => { f.write_str("7")?; }
+            Card::Six => { f.write_str("6")?; }
+            Card::Five => { f.write_str("5")?; }
+            Card::Four => { f.write_str("4")?; }
+            Card::Three => { f.write_str("3")?; }
+            Card::Two => { f.write_str("2")?; }
+        }
+
+        Ok(())
+    }
+}
+
+const CARD_LABELS: &str = "A, K, Q, J, T, 9, 8, 7, 6, 5, 4, 3 or 2";
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.chars().count() != 1 {
+            return Err( ParseError::at(s, s, ParseErrorKind::Unexpected { expected: CARD_LABELS, found: s.to_string() }) );
+        }
+
+        match s.chars().next().unwrap() {
+            'A' => Ok(Card::Ace),
+            'K' => Ok(Card::King),
+            'Q' => Ok(Card::Queen),
+            'J' => Ok(Card::Jack),
+            'T' => Ok(Card::Ten),
+            '9' => Ok(Card::Nine),
+            '8' => Ok(Card::Eight),
+            '7' => Ok(Card::Seven),
+            '6' => Ok(Card::Six),
+            '5' => Ok(Card::Five),
+            '4' => Ok(Card::Four),
+            '3' => Ok(Card::Three),
+            '2' => Ok(Card::Two),
+            c => Err( ParseError::at(s, s, ParseErrorKind::Unexpected { expected: CARD_LABELS, found: c.to_string() }) )
+        }
+    }
+}
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+// generates `size` hands with bids between 1 and 1000
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let hand: String = (0..5).map(|_| *rng.pick(&CARDS)).collect();
+
+            format!("{} {}", hand, rng.range(1..1001))
+        })
+        .collect()
+}
+
+// the hands relabeled and in a random order, with the bids shuffled among them
+//
+// relabeling keeps the type of each hand, the jokers of part 2 keep their label
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    let mut hands = parse_lines(lines)?;
+
+    let labels: Vec<char> = CARDS.iter().copied().filter(|label| *label != 'J').collect();
+    let mut relabeled = labels.clone();
+    rng.shuffle(&mut relabeled);
+
+    let mut bids: Vec<u32> = hands.iter().map(|hand| hand.bid).collect();
+    rng.shuffle(&mut bids);
+    rng.shuffle(&mut hands);
+
+    Ok(
+        hands.iter().zip(bids)
+            .map(|(hand, bid)| {
+                let cards: String =
+                    hand.hand.to_string().chars()
+                        .map(|label| labels.iter().position(|other| *other == label).map_or(label, |index| relabeled[index]))
+                        .collect();
+
+                format!("{} {}", cards, bid)
+            })
+            .collect())
+}
+
+#[cfg(test)]
+mod test {
+    mod process_lines {
+        use crate::Solution;
+        use crate::day07::{Day07, Hand, parse_lines, process_lines};
+        use crate::shell::Shell;
+        use crate::trace;
+
+        const SAMPLE_INPUT: &str = 
+"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+        
+        #[test]
+        fn it_should_sort_hands_by_rank() {
+            assert_eq!(
+                vec![
+                    "32T3K".parse::<Hand>().unwrap(),
+                    "KTJJT".parse::<Hand>().unwrap(),
+                    "KK677".parse::<Hand>().unwrap(),
+                    "T55J5".parse::<Hand>().unwrap(),
+                    "QQQJA".parse::<Hand>().unwrap()
+                ],
+
+                process_lines(
+                    &parse_lines(&SAMPLE_INPUT.split("\n").map( &str::to_string ).collect::<Vec<String>>()).unwrap()
+                ).iter()
+                    .map( |hand| hand.hand.clone() )
+                    .collect::<Vec<Hand>>(),
+                "Hands must be sorted by rank - lowest rank first"
+            );
+        }
+
+        #[test]
+        fn it_should_trace_the_win_type_of_each_hand() {
+            let day = Day07::parse(&SAMPLE_INPUT.split("\n").map( &str::to_string ).collect::<Vec<String>>()).unwrap();
+
+            let (_, lines) = trace::capture(1, || day.part2().unwrap());
+
+            assert_eq!(
+                vec![
+                    "rank 1: 32T3K OnePair, bid 765 wins 765",
+                    "rank 2: KK677 TwoPair, bid 28 wins 56",
+                    "rank 3: T55J5 FourOfAKind, bid 684 wins 2052",
+                    "rank 4: QQQJA FourOfAKind, bid 483 wins 1932",
+                    "rank 5: KTJJT FourOfAKind, bid 220 wins 1100"
+                ],
+                lines
+            );
+        }
+
+        #[test]
+        fn it_should_rank_a_hand_with_the_rules_of_both_parts() {
+            let day = Day07::parse(&SAMPLE_INPUT.split("\n").map( &str::to_string ).collect::<Vec<String>>()).unwrap();
+
+            assert_eq!(
+                Ok("part 1: rank 3 of 5, TwoPair, bid 28 wins 84\npart 2: rank 2 of 5, TwoPair, bid 28 wins 56".to_string()),
+                day.execute("rank KK677")
+            );
+
+            assert_eq!(
+                Ok("part 1: not in the input, would rank 2 of 6, OnePair\npart 2: not in the input, would rank 3 of 6, ThreeOfAKind".to_string()),
+                day.execute("rank T55J4")
+            );
+
+            assert!(day.execute("rank KK6").is_err());
+        }
+    }
+
+    mod parse_lines {
+        use crate::day07::parse_lines;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        fn lines(input: &str) -> Vec<String> {
+            input.split("\n").map( &str::to_string ).collect()
+        }
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_input() {
+            assert_eq!(
+                Some(ParseError::new(2, 3, ParseErrorKind::Unexpected { expected: "A, K, Q, J, T, 9, 8, 7, 6, 5, 4, 3 or 2", found: "X".to_string() })),
+
+                parse_lines(&lines("32T3K 765\nT5XJ5 684")).err(),
+                "unknown card"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(1, 1, ParseErrorKind::InvalidLength { expected: 5, found: 4 })),
+
+                parse_lines(&lines("32T3 765")).err(),
+                "hand with too few cards"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(1, 7, ParseErrorKind::InvalidNumber("7x5".to_string()))),
+
+                parse_lines(&lines("32T3K 7x5")).err(),
+                "invalid bid"
+            );
+        }
+    }
+
+    mod hand_new {
+        use crate::day07::{Card, Hand};
+
+        #[test]
+        fn it_should_only_accept_five_cards() {
+            assert_eq!(Some("KTJJT".parse::<Hand>().unwrap()), Hand::new(vec![Card::King, Card::Ten, Card::Jack, Card::Jack, Card::Ten]));
+            assert_eq!(None, Hand::new(vec![Card::King, Card::Ten, Card::Jack, Card::Jack]));
+            assert_eq!(None, Hand::new(vec![Card::Two; 6]));
+        }
+    }
+
+    mod hand_get_win_type {
+        use crate::day07::{WinType, Hand};
+
+        #[test]
+        fn it_should_determine_the_win_type_for_each_hand() {
+            assert_eq!(
+                WinType::OnePair,
+
+                "32T3K"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::TwoPair,
+
+                "KK677"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::TwoPair,
+
+                "KTJJT"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::ThreeOfAKind,
+
+                "T55J5"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::ThreeOfAKind,
+
+                "QQQJA"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::FullHouse,
+
+                "QQQAA"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::FourOfAKind,
+
+                "QQQQ2"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::FiveOfAKind,
+
+                "22222"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type(),
+                "failed to recognize correct win type"
+            );
+        }
+    }
+
+    mod hand_get_win_type_part2 {
+        use crate::day07::{WinType, Hand};
+
+        #[test]
+        fn it_should_determine_the_win_type_for_each_hand() {
+            assert_eq!(
+                WinType::OnePair,
+
+                "32T3K"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::TwoPair,
+
+                "KK677"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::ThreeOfAKind,
+
+                "T5525"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::ThreeOfAKind,
+
+                "TJ525"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with one joker"
+            );
+
+            assert_eq!(
+                WinType::ThreeOfAKind,
+
+                "TJJ25"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with two jokers"
+            );
+
+            assert_eq!(
+                WinType::FullHouse,
+
+                "QQQAA"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::FullHouse,
+
+                "QQJAA"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with one joker"
+            );
+
+            assert_eq!(
+                WinType::FourOfAKind,
+
+                "QQQQ2"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::FourOfAKind,
+
+                "QQQJ2"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with one joker"
+            );
+
+            assert_eq!(
+                WinType::FourOfAKind,
+
+                "QQJJ2"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with two jokers"
+            );
+
+            assert_eq!(
+                WinType::FourOfAKind,
+
+                "QJJJ2"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with three jokers"
+            );
+
+            assert_eq!(
+                WinType::FiveOfAKind,
+
+                "22222"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type"
+            );
+
+            assert_eq!(
+                WinType::FiveOfAKind,
+
+                "2222J"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with one joker"
+            );   
+
+            assert_eq!(
+                WinType::FiveOfAKind,
+
+                "222JJ"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with two jokers"
+            );
+
+            assert_eq!(
+                WinType::FiveOfAKind,
+
+                "22JJJ"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with three jokers"
+            );
+
+            assert_eq!(
+                WinType::FiveOfAKind,
+
+                "2JJJJ"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with four jokers"
+            );
+
+            assert_eq!(
+                WinType::FiveOfAKind,
+
+                "JJJJJ"
+                    .parse::<Hand>().unwrap()
+                    .get_win_type_part2(),
+                "failed to recognize correct win type for a hand with five jokers"
+            );
+        }
+    }
+
+    mod anonymize {
+        use crate::day07::{anonymize, parse_lines, HandAndBid};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_keep_the_hand_types_and_the_bids() {
+            let lines: Vec<String> = ["32T3K 765", "T55J5 684", "KK677 28", "KTJJT 220", "QQQJA 483"].map(|line| line.to_string()).to_vec();
+
+            let anonymized = anonymize(&mut Rng::new(5), &lines).unwrap();
+
+            let types = |hands: &[HandAndBid]| {
+                let mut types: Vec<_> = hands.iter().map(|hand| (hand.hand.get_win_type(), hand.hand.get_win_type_part2())).collect();
+                types.sort();
+                types
+            };
+            let bids = |hands: &[HandAndBid]| {
+                let mut bids: Vec<u32> = hands.iter().map(|hand| hand.bid).collect();
+                bids.sort();
+                bids
+            };
+
+            let (hands, anonymized_hands) = (parse_lines(&lines).unwrap(), parse_lines(&anonymized).unwrap());
+            assert_eq!(types(&hands), types(&anonymized_hands));
+            assert_eq!(bids(&hands), bids(&anonymized_hands));
+            assert_eq!(lines.join("").matches('J').count(), anonymized.join("").matches('J').count());
+        }
+    }
+}
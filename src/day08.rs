@@ -0,0 +1,755 @@
+use std::{io, str::FromStr, collections::{HashMap, HashSet}};
+
+use crate::Solution;
+use crate::math::{self, MathError};
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::shell::{self, Command, Commands};
+
+pub struct Day08 {
+    pub instructions: String,
+    pub map: HashMap<String, Node>
+}
+
+impl Solution for Day08 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let (instructions, map) = parse_instructions_and_map(lines)?;
+
+        Ok(Day08 { instructions, map })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        Ok(count_steps_to_reach_zzz(&self.instructions, &self.map)?.to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        Ok(count_steps_to_simultaneously_reach_nodes_ending_with_z(&self.instructions, &self.map)?.to_string())
+    }
+}
+
+impl Commands for Day08 {
+    const COMMANDS: &'static [Command<Day08>] = &[
+        Command { name: "walk", arguments: "<node> <steps>", help: "follows the instructions from the node", run: walk_command }
+    ];
+}
+
+// "walk AAA 2" prints "AAA -R-> CCC -L-> ZZZ", the instructions repeat as often as needed
+fn walk_command(day: &Day08, arguments: &[&str]) -> Result<String, String> {
+    let [key, steps] = shell::arguments(arguments)?;
+    let steps: usize = shell::parse_argument(steps, "number of steps")?;
+
+    let mut node = day.map.get(key).ok_or(format!("unknown node: {}", key))?;
+    let mut path = node.key.clone();
+
+    for direction in day.instructions.chars().cycle().take(steps) {
+        let next = if direction == 'L' { &node.left } else { &node.right };
+        node = day.map.get(next).ok_or(format!("node {} links to the unknown node {}", node.key, next))?;
+
+        path += &format!(" -{}-> {}", direction, node.key);
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+fn process(lines: &[String]) -> u32 {
+    let (instructions, map) = parse_instructions_and_map(lines).unwrap();
+
+    count_steps_to_reach_zzz(&instructions, &map).unwrap()
+}
+
+#[cfg(test)]
+fn process_part2(lines: &[String]) -> usize {
+    let (instructions, map) = parse_instructions_and_map(lines).unwrap();
+
+    count_steps_to_simultaneously_reach_nodes_ending_with_z(&instructions, &map).unwrap()
+}
+
+// every link must name a node of the network and at least one node must end with A
+pub fn parse_instructions_and_map(lines: &[String]) -> Result<(String, HashMap<String, Node>), ParseError> {
+    let mut lines = lines.iter();
+
+    let instructions = lines.next().cloned().ok_or(ParseError::new(1, 1, ParseErrorKind::Missing("instruction line")))?;
+    if instructions.is_empty() {
+        return Err(ParseError::new(1, 1, ParseErrorKind::Missing("instructions")));
+    }
+    if let Some((index, direction)) = instructions.char_indices().find(|(_, direction)| *direction != 'L' && *direction != 'R') {
+        return Err(ParseError::at(&instructions, &instructions[index..], ParseErrorKind::Unexpected { expected: "L or R", found: direction.to_string() }));
+    }
+
+    let separation_line = lines.next().ok_or(ParseError::new(2, 1, ParseErrorKind::Missing("separation line between instructions and network")))?;
+    if !separation_line.is_empty() {
+        return Err(ParseError::new(2, 1, ParseErrorKind::Unexpected { expected: "empty line", found: separation_line.clone() }));
+    }
+
+    let mut map = HashMap::new();
+    let mut network = vec![];
+
+    for (index, line) in lines.enumerate() {
+        // network starts at the third line
+        let node = line.parse::<Node>().map_err(|err| err.on_line(index+3))?;
+        map.insert(node.key.clone(), Node { key: node.key.clone(), left: node.left.clone(), right: node.right.clone() });
+        network.push((index+3, line, node));
+    }
+
+    for (line_number, line, node) in &network {
+        // "<key> = (<left>, <right>)", as checked by the node parser
+        let left_start = node.key.len() + " = (".len();
+        let right_start = left_start + node.left.len() + ", ".len();
+
+        for (key, start) in [(&node.left, left_start), (&node.right, right_start)] {
+            if !map.contains_key(key) {
+                return Err(
+                    ParseError::at(line, &line[start..start + key.len()], ParseErrorKind::Unexpected { expected: "key of a node in the network", found: key.clone() })
+                        .on_line(*line_number));
+            }
+        }
+    }
+
+    if !map.keys().any(|key| key.ends_with('A')) {
+        return Err(ParseError::new(network.len() + 3, 1, ParseErrorKind::Missing("node ending with A")));
+    }
+
+    Ok((instructions, map))
+}
+
+fn next_node<'a>(map: &'a HashMap<String, Node>, node: &Node, direction: char) -> io::Result<&'a Node> {
+    let key =
+        match direction {
+            'L' => &node.left,
+            'R' => &node.right,
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("invalid direction: '{}'", direction)))
+        };
+
+    map.get(key)
+        .ok_or(io::Error::new(io::ErrorKind::InvalidData, format!("node {} links to the unknown node {}", node.key, key)))
+}
+
+// fails if AAA or ZZZ is missing or ZZZ can't be reached
+pub fn count_steps_to_reach_zzz(instructions: &str, map: &HashMap<String, Node>) -> io::Result<u32> {
+    const START_KEY: &str = "AAA";
+    const TARGET_KEY: &str = "ZZZ";
+
+    for key in [START_KEY, TARGET_KEY] {
+        if !map.contains_key(key) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("node {} not found", key)));
+        }
+    }
+
+    // by then a pair of node and position in the instructions has repeated
+    let max_steps = map.len() * instructions.len();
+
+    let mut steps = 0;
+    let mut node = &map[START_KEY];
+
+    while node.key != TARGET_KEY {
+        for direction in instructions.chars() {
+            node = next_node(map, node, direction)?;
+            steps += 1;
+        }
+
+        if steps > max_steps {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, format!("{} can't be reached from {}", TARGET_KEY, START_KEY)));
+        }
+    }
+
+    Ok(steps as u32)
+}
+
+// fails without ..A nodes, if a path stops reaching ..Z nodes, or if the paths never reach ..Z nodes together
+pub fn count_steps_to_simultaneously_reach_nodes_ending_with_z(instructions: &str, map: &HashMap<String, Node>) -> io::Result<usize> {
+    // more combinations of one ..Z node per cycle of each path are not tried
+    const MAX_COMBINATIONS: usize = 1_000_000;
+
+    let directions: Vec<char> = instructions.chars().collect();
+
+    let paths: Vec<ZSteps> =
+        map.values()
+            .filter(|node| node.key.ends_with("A"))
+            .map(|node| find_z_steps(&directions, map, node))
+            .collect::<io::Result<_>>()?;
+
+    if paths.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "no start nodes ending with A"));
+    }
+
+    // a common step before some path repeats is one of the steps before the cycle of that path
+    let before_cycles =
+        paths.iter()
+            .flat_map(|path| path.before_cycle.iter().copied())
+            .filter(|steps| paths.iter().all(|path| path.contains(*steps)))
+            .min();
+    if let Some(steps) = before_cycles {
+        return Ok(steps);
+    }
+
+    if paths.iter().any(|path| path.in_cycle.is_empty()) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "a path never reaches a node ending with Z twice"));
+    }
+
+    let combinations = paths.iter().try_fold(1usize, |combinations, path| combinations.checked_mul(path.in_cycle.len()));
+    if combinations.is_none_or(|combinations| combinations > MAX_COMBINATIONS) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "too many nodes ending with Z per cycle"));
+    }
+
+    // the earliest common step of every combination of one ..Z step per cycle
+    let mut earliest: Option<usize> = None;
+    let mut indices = vec![0; paths.len()];
+
+    loop {
+        let cycles =
+            paths.iter().zip(indices.iter())
+                .map(|(path, index)| Cycle { offset: path.in_cycle[*index], size: path.cycle_size })
+                .collect();
+
+        match count_steps_until_all_cycles_are_complete(cycles) {
+            Ok(steps) => earliest = Some(earliest.map_or(steps, |earliest| earliest.min(steps))),
+            Err(MathError::NoSolution) => {}
+            Err(err) => return Err(err.into())
+        }
+
+        let Some(position) = (0..paths.len()).find(|position| indices[*position] + 1 < paths[*position].in_cycle.len()) else {
+            break;
+        };
+        indices[position] += 1;
+        indices[..position].fill(0);
+    }
+
+    Ok(earliest.ok_or(MathError::NoSolution)?)
+}
+
+// follows the path until a pair of node and position in the instructions repeats
+fn find_z_steps(directions: &[char], map: &HashMap<String, Node>, start: &Node) -> io::Result<ZSteps> {
+    let mut first_steps: HashMap<(&str, usize), usize> = HashMap::new();
+    let mut z_steps = vec![];
+    let mut node = start;
+    let mut steps = 0;
+
+    loop {
+        let position = steps % directions.len();
+
+        if let Some(&cycle_start) = first_steps.get(&(node.key.as_str(), position)) {
+            let (before_cycle, in_cycle) = z_steps.iter().partition(|z_step| **z_step < cycle_start);
+
+            return Ok(ZSteps { before_cycle, cycle_start, cycle_size: steps - cycle_start, in_cycle });
+        }
+        first_steps.insert((node.key.as_str(), position), steps);
+
+        if node.key.ends_with("Z") {
+            z_steps.push(steps);
+        }
+
+        node = next_node(map, node, directions[position])?;
+        steps += 1;
+    }
+}
+
+fn count_steps_until_all_cycles_are_complete(cycles: Vec<Cycle>) -> Result<usize, MathError> {
+    let congruences: Vec<(i128, i128)> =
+        cycles.iter()
+            .map(|cycle| (cycle.offset as i128, cycle.size as i128))
+            .collect();
+
+    let (steps, combined_size) = math::chinese_remainder(&congruences)?;
+
+    // the common step must not come before any path reached its first ..Z node
+    let first_steps = congruences.iter().map(|(offset, _)| *offset).max().unwrap_or_default();
+    let steps =
+        if steps < first_steps {
+            (first_steps - steps + combined_size - 1) / combined_size * combined_size + steps
+        } else {
+            steps
+        };
+
+    usize::try_from(steps).map_err(|_| MathError::Overflow)
+}
+
+// steps at which a path reaches ..Z nodes: some before it starts repeating at cycle_start,
+// the others in_cycle and again every cycle_size steps
+#[derive(Debug)]
+struct ZSteps {
+    before_cycle: Vec<usize>,
+    cycle_start: usize,
+    cycle_size: usize,
+    in_cycle: Vec<usize>
+}
+
+impl ZSteps {
+    fn contains(&self, steps: usize) -> bool {
+        if steps < self.cycle_start {
+            self.before_cycle.contains(&steps)
+        } else {
+            self.in_cycle.contains(&(self.cycle_start + (steps - self.cycle_start) % self.cycle_size))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Cycle {
+    offset: usize,
+    size: usize
+}
+
+#[derive(Debug)]
+pub struct Node {
+    pub key: String,
+    pub left: String,
+    pub right: String
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, links) = s.split_once(" = ").ok_or(ParseError::at_end(s, ParseErrorKind::MissingDelimiter(" = ")))?;
+
+        let links = 
+            links
+                .strip_prefix("(").ok_or(ParseError::at(s, links, ParseErrorKind::MissingDelimiter("(")))?
+                .strip_suffix(")").ok_or(ParseError::at_end(s, ParseErrorKind::MissingDelimiter(")")))?;
+
+        let (left, right) = links.split_once(", ").ok_or(ParseError::at(s, links, ParseErrorKind::MissingDelimiter(", ")))?;
+
+        Ok(
+            Node {
+                key: key.to_string(),
+                left: left.to_string(),
+                right: right.to_string()
+            }
+        )
+    }
+}
+
+// generates a network with `size` start nodes, one of them is AAA
+//
+// the path of each start node runs through a chain of nodes into its own ..Z
+// node, and from there loops back into the chain - the length of the chain is
+// a multiple of the number of instructions, so the ..Z node is always reached
+// at the end of the instructions
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    const ALPHABET: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY0123456789";
+    const PRIMES: [usize; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+    let size = size.max(1);
+    let instructions: String = (0..rng.range(2..13)).map(|_| if rng.chance(50) { 'L' } else { 'R' }).collect();
+    let chain_lengths: Vec<usize> = (0..size).map(|_| rng.pick(&PRIMES) * instructions.len()).collect();
+
+    // keys of the nodes within the chains never end with A or Z
+    let mut width = 3;
+    while ALPHABET.len().pow(width as u32 - 1) < size || ALPHABET.len().pow(width as u32) < chain_lengths.iter().sum() {
+        width += 1;
+    }
+
+    let key = |mut index: usize, width: usize| {
+        let mut digits = vec![ALPHABET[0]; width];
+        for digit in digits.iter_mut().rev() {
+            *digit = ALPHABET[index % ALPHABET.len()];
+            index /= ALPHABET.len();
+        }
+
+        String::from_utf8(digits).unwrap()
+    };
+
+    let mut lines = vec![];
+    let mut next_chain_node = 0;
+
+    for (start, chain_length) in chain_lengths.into_iter().enumerate() {
+        let (start_key, end_key) =
+            if start == 0 {
+                ("AAA".to_string(), "ZZZ".to_string())
+            } else {
+                (key(start, width - 1) + "A", key(start, width - 1) + "Z")
+            };
+
+        // start node, the nodes in between and the end node
+        let mut chain = vec![start_key];
+        chain.extend((1..chain_length).map(|index| key(next_chain_node + index, width)));
+        chain.push(end_key);
+        next_chain_node += chain_length;
+
+        for (step, node) in chain.iter().enumerate() {
+            let (step, next_node) =
+                if step + 1 < chain.len() {
+                    (step, &chain[step + 1])
+                } else {
+                    (0, &chain[1])
+                };
+
+            // the other direction leads somewhere within the chain
+            let other_node = &chain[rng.range(1..chain.len() as u64) as usize];
+
+            lines.push(
+                if instructions.as_bytes()[step % instructions.len()] == b'L' {
+                    format!("{} = ({}, {})", node, next_node, other_node)
+                } else {
+                    format!("{} = ({}, {})", node, other_node, next_node)
+                });
+        }
+    }
+
+    rng.shuffle(&mut lines);
+
+    [instructions, String::new()].into_iter().chain(lines).collect()
+}
+
+// the same network with other node keys and the nodes in a random order, possibly with left and right swapped
+//
+// AAA and ZZZ keep their keys and the other keys keep ending with A or Z if they did
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    const LETTERS: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY";
+
+    let (instructions, _) = parse_instructions_and_map(lines)?;
+    let nodes: Vec<Node> = lines[2..].iter().map(|line| line.parse().expect("the line was parsed")).collect();
+
+    let mut keys: HashMap<&str, String> = HashMap::from([("AAA", "AAA".to_string()), ("ZZZ", "ZZZ".to_string())]);
+    let mut used: HashSet<String> = keys.values().cloned().collect();
+    // new keys drawn so far by length and last letter, AAA and ZZZ are of none of these shapes
+    let mut drawn: HashMap<(usize, Option<char>), usize> = HashMap::new();
+
+    for key in nodes.iter().flat_map(|node| [&node.key, &node.left, &node.right]) {
+        if keys.contains_key(key.as_str()) || key.is_empty() {
+            continue;
+        }
+
+        // keys get longer once all keys of their length are taken
+        let last = key.chars().last().filter(|last| *last == 'A' || *last == 'Z');
+        let mut length = key.chars().count();
+        let random_letters = |length: usize| length - last.map_or(0, |_| 1);
+        while drawn.get(&(length, last)).copied().unwrap_or(0) >= LETTERS.len().saturating_pow(random_letters(length) as u32) {
+            length += 1;
+        }
+        *drawn.entry((length, last)).or_insert(0) += 1;
+
+        let new_key = loop {
+            let mut new_key: String = (0..random_letters(length)).map(|_| char::from(*rng.pick(LETTERS))).collect();
+            new_key.extend(last);
+
+            if used.insert(new_key.clone()) {
+                break new_key;
+            }
+        };
+        keys.insert(key, new_key);
+    }
+
+    let swap = rng.chance(50);
+    let instructions: String =
+        if swap {
+            instructions.chars().map(|direction| if direction == 'L' { 'R' } else { 'L' }).collect()
+        } else {
+            instructions
+        };
+
+    let mut network: Vec<String> =
+        nodes.iter()
+            .map(|node| {
+                let (left, right) = if swap { (&node.right, &node.left) } else { (&node.left, &node.right) };
+                let key = |key: &String| keys.get(key.as_str()).cloned().unwrap_or_default();
+
+                format!("{} = ({}, {})", key(&node.key), key(left), key(right))
+            })
+            .collect();
+    rng.shuffle(&mut network);
+
+    Ok([instructions, String::new()].into_iter().chain(network).collect())
+}
+
+#[cfg(test)]
+mod test {
+    mod process {
+        use crate::day08::process;
+
+        const SAMPLE_INPUT_2_STEPS: &str =
+"RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        const SAMPLE_INPUT_6_STEPS: &str =
+"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        #[test]
+        fn it_should_count_steps_needed_to_traverse_through_the_network() {
+            assert_eq!(
+                2,
+
+                process(&SAMPLE_INPUT_2_STEPS.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()),
+                "instructions for network that takes 2 steps"
+            );
+
+            assert_eq!(
+                6,
+
+                process(&SAMPLE_INPUT_6_STEPS.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()),
+                "instructions for network that takes 6 steps"
+            );
+        }
+    }
+
+    mod walk_command {
+        use crate::Solution;
+        use crate::day08::Day08;
+        use crate::shell::Shell;
+
+        const SAMPLE_INPUT_6_STEPS: &str =
+"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        #[test]
+        fn it_should_repeat_the_instructions_while_walking() {
+            let day = Day08::parse(&SAMPLE_INPUT_6_STEPS.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()).unwrap();
+
+            assert_eq!(Ok("AAA -L-> BBB -L-> AAA -R-> BBB -L-> AAA".to_string()), day.execute("walk AAA 4"));
+            assert_eq!(Ok("ZZZ".to_string()), day.execute("walk ZZZ 0"));
+            assert_eq!(Err("unknown node: CCC\nusage: walk <node> <steps>".to_string()), day.execute("walk CCC 1"));
+        }
+    }
+
+    mod count_steps_to_reach_zzz {
+        use crate::day08::{count_steps_to_reach_zzz, parse_instructions_and_map};
+
+        fn steps(input: &str) -> Result<u32, String> {
+            let (instructions, map) = parse_instructions_and_map(&input.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()).unwrap();
+
+            count_steps_to_reach_zzz(&instructions, &map).map_err(|err| err.to_string())
+        }
+
+        #[test]
+        fn it_should_fail_without_a_path_from_aaa_to_zzz() {
+            assert_eq!(Err("node AAA not found".to_string()), steps("LR\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)"));
+            assert_eq!(Err("node ZZZ not found".to_string()), steps("LR\n\nAAA = (AAA, AAA)"));
+            assert_eq!(Err("ZZZ can't be reached from AAA".to_string()), steps("LR\n\nAAA = (AAA, AAA)\nZZZ = (AAA, AAA)"));
+        }
+    }
+
+    mod process_part2 {
+        use crate::day08::{count_steps_to_simultaneously_reach_nodes_ending_with_z, parse_instructions_and_map, process_part2};
+        use crate::math::MathError;
+
+        const SAMPLE_INPUT_6_STEPS: &str =
+"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        #[test]
+        fn it_should_count_steps_needed_to_simultaneously_traverse_through_the_network() {
+            assert_eq!(
+                6,
+
+                process_part2(&SAMPLE_INPUT_6_STEPS.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()),
+                "instructions for network that takes 6 steps"
+            );
+        }
+
+        #[test]
+        fn it_should_count_steps_for_cycles_not_starting_at_the_start_node() {
+            // 11A reaches 11Z after 2, 5, 8, ... steps, 22A reaches 22Z after 1, 3, 5, ... steps
+            let input =
+"L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11D, 11D)
+11D = (11Z, 11Z)
+22A = (22Z, 22Z)
+22Z = (22E, 22E)
+22E = (22Z, 22Z)";
+
+            assert_eq!(5, process_part2(&input.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()));
+        }
+
+        #[test]
+        fn it_should_count_steps_for_cycles_with_several_nodes_ending_with_z() {
+            // 11A reaches 11Z or 12Z after 1, 3, 6, 8, 11, 13, ... steps, 22A reaches 22Z after 4, 8, 12, ... steps
+            let input =
+"L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (12Z, 12Z)
+12Z = (11C, 11C)
+11C = (11D, 11D)
+11D = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22D, 22D)
+22D = (22Z, 22Z)
+22Z = (22B, 22B)";
+
+            assert_eq!(8, process_part2(&input.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()));
+        }
+
+        #[test]
+        fn it_should_count_steps_before_the_paths_repeat() {
+            // both paths reach a ..Z node once, after 2 steps
+            let input =
+"L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11C, 11C)
+11C = (11C, 11C)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22A, 22A)";
+
+            assert_eq!(2, process_part2(&input.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()));
+        }
+
+        #[test]
+        fn it_should_report_nodes_that_never_end_with_z_at_the_same_time() {
+            // 11A reaches 11Z after 1, 3, 5, ... steps, 22A reaches 22Z after 2, 4, 6, ... steps
+            let input =
+"L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)";
+
+            let (instructions, map) = parse_instructions_and_map(&input.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()).unwrap();
+
+            assert_eq!(
+                Err(MathError::NoSolution.to_string()),
+                count_steps_to_simultaneously_reach_nodes_ending_with_z(&instructions, &map).map_err(|err| err.to_string())
+            );
+        }
+
+        #[test]
+        fn it_should_report_paths_that_never_reach_a_node_ending_with_z() {
+            let input =
+"LR
+
+11A = (11B, 11B)
+11B = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22A, 22A)";
+
+            let (instructions, map) = parse_instructions_and_map(&input.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()).unwrap();
+
+            assert_eq!(
+                Err("a path never reaches a node ending with Z twice".to_string()),
+                count_steps_to_simultaneously_reach_nodes_ending_with_z(&instructions, &map).map_err(|err| err.to_string())
+            );
+        }
+    }
+
+    mod parse_instructions_and_map {
+        use crate::day08::parse_instructions_and_map;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        fn lines(input: &str) -> Vec<String> {
+            input.split("\n").map(|e| e.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_input() {
+            assert_eq!(
+                Some(ParseError::new(1, 3, ParseErrorKind::Unexpected { expected: "L or R", found: "X".to_string() })),
+
+                parse_instructions_and_map(&lines("LRX\n\nAAA = (BBB, BBB)")).err(),
+                "invalid direction"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(2, 1, ParseErrorKind::Unexpected { expected: "empty line", found: "AAA = (BBB, BBB)".to_string() })),
+
+                parse_instructions_and_map(&lines("LR\nAAA = (BBB, BBB)")).err(),
+                "missing separation line"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(4, 16, ParseErrorKind::MissingDelimiter(")"))),
+
+                parse_instructions_and_map(&lines("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ")).err(),
+                "missing closing parenthesis"
+            );
+        }
+
+        #[test]
+        fn it_should_report_links_to_unknown_nodes_and_missing_start_nodes() {
+            assert_eq!(
+                Some(ParseError::new(4, 13, ParseErrorKind::Unexpected { expected: "key of a node in the network", found: "ZZZ".to_string() })),
+
+                parse_instructions_and_map(&lines("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)")).err(),
+                "unknown right node"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(4, 1, ParseErrorKind::Missing("node ending with A"))),
+
+                parse_instructions_and_map(&lines("LR\n\nBBB = (BBB, BBB)")).err(),
+                "no start node"
+            );
+
+            assert_eq!(
+                Some(ParseError::new(1, 1, ParseErrorKind::Missing("instructions"))),
+
+                parse_instructions_and_map(&lines("\n\nAAA = (AAA, AAA)")).err(),
+                "no instructions"
+            );
+        }
+    }
+
+    mod anonymize {
+        use std::collections::HashSet;
+
+        use crate::Solution;
+        use crate::day08::{anonymize, generate, Day08};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_rename_the_nodes_but_keep_the_paths() {
+            let lines = generate(&mut Rng::new(1), 3);
+
+            for seed in 0..4 {
+                let anonymized = anonymize(&mut Rng::new(seed), &lines).unwrap();
+
+                assert_ne!(lines, anonymized);
+                assert_eq!(3, anonymized.iter().filter(|line| line.split(" = ").next().unwrap().ends_with('A')).count());
+                assert!(anonymized.iter().any(|line| line.starts_with("AAA = ")));
+                assert_eq!(Day08::parse(&lines).unwrap().part1().unwrap(), Day08::parse(&anonymized).unwrap().part1().unwrap());
+                assert_eq!(Day08::parse(&lines).unwrap().part2().unwrap(), Day08::parse(&anonymized).unwrap().part2().unwrap());
+            }
+        }
+
+        #[test]
+        fn it_should_lengthen_the_keys_once_all_keys_of_their_length_are_taken() {
+            const CHARS: &[u8] = b"0123456789BCDEFGHIJKLMNOPQRSTUVWXY";
+
+            // more keys ending with A than the 24 x 24 keys of three letters ending with A
+            let keys: Vec<String> = (0..700).map(|index| format!("{}{}A", char::from(CHARS[index / CHARS.len()]), char::from(CHARS[index % CHARS.len()]))).collect();
+            let lines: Vec<String> = ["L".to_string(), String::new()].into_iter().chain(keys.iter().map(|key| format!("{} = ({}, {})", key, key, key))).collect();
+
+            let anonymized = anonymize(&mut Rng::new(1), &lines).unwrap();
+            let new_keys: HashSet<&str> = anonymized[2..].iter().map(|line| line.split(" = ").next().unwrap()).collect();
+
+            assert!(Day08::parse(&anonymized).is_ok());
+            assert_eq!(700, new_keys.len());
+            assert!(new_keys.iter().all(|key| key.ends_with('A')));
+            assert_eq!(24 * 24, new_keys.iter().filter(|key| key.len() == 3).count());
+            assert_eq!(700 - 24 * 24, new_keys.iter().filter(|key| key.len() == 4).count());
+        }
+    }
+}
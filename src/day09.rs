@@ -0,0 +1,213 @@
+use std::io;
+
+use crate::Solution;
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::trace;
+
+pub struct Day09 {
+    pub sequences: Vec<Vec<isize>>
+}
+
+impl Solution for Day09 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let sequences = 
+            lines.iter()
+                .enumerate()
+                .map(|(index, line)| parse_sequence(line).map_err(|err| err.on_line(index+1)))
+                .collect::<Result<Vec<Vec<isize>>, ParseError>>()?;
+
+        Ok(Day09 { sequences })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        Ok(self.sequences.iter().map(|e| discrecte_extrapolation_behind_end(e)).sum::<isize>().to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        Ok(self.sequences.iter().map(|e| discrecte_extrapolation_before_beginning(e)).sum::<isize>().to_string())
+    }
+}
+
+pub fn parse_sequence(line: &str) -> Result<Vec<isize>, ParseError> {
+    let numbers: Vec<isize> = 
+        line
+            .split_whitespace()
+            .map(|e| e.parse::<isize>().map_err(|_| ParseError::at(line, e, ParseErrorKind::InvalidNumber(e.to_string()))) )
+            .collect::<Result<Vec<isize>, ParseError>>()?;
+
+    if numbers.is_empty() {
+        return Err(ParseError::at_end(line, ParseErrorKind::Missing("sequence of numbers")));
+    }
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+fn process(line: &str) -> isize {
+    discrecte_extrapolation_behind_end(&parse_sequence(line).unwrap())
+}
+
+#[cfg(test)]
+fn process_part2(line: &str) -> isize {
+    discrecte_extrapolation_before_beginning(&parse_sequence(line).unwrap())
+}
+
+pub fn discrecte_extrapolation_behind_end(numbers: &[isize]) -> isize {
+    let derivatives = discrete_derivatives(numbers);
+
+    // extrapolate the next value behind the end of the n th derivative sequence
+    // by adding the last value of the n+1 nth derivative sequence
+    let next_value_of_first_derivative = 
+        derivatives.iter().rev().fold(
+            0isize, 
+            |acc, derivative| derivative.last().unwrap() + acc 
+        );
+
+    // extrapolate next number
+    let next_value = numbers.last().unwrap() + next_value_of_first_derivative;
+    trace_pyramid(numbers, &derivatives, "next", next_value);
+
+    next_value
+}
+
+pub fn discrecte_extrapolation_before_beginning(numbers: &[isize]) -> isize {
+    let derivatives = discrete_derivatives(numbers);
+
+    // extrapolate the next value before the beginning of the n th derivative sequence
+    // by substracting the first value of the n+1 nth derivative sequence
+    let next_value_of_first_derivative = 
+        derivatives.iter().rev().fold(
+            0isize, 
+            |acc, derivative| derivative.first().unwrap() - acc 
+        );
+
+    // extrapolate next number
+    let previous_value = numbers.first().unwrap() - next_value_of_first_derivative;
+    trace_pyramid(numbers, &derivatives, "previous", previous_value);
+
+    previous_value
+}
+
+// the sequence and its derivatives below each other, each one indented a bit more
+fn trace_pyramid(numbers: &[isize], derivatives: &[Vec<isize>], direction: &str, value: isize) {
+    if trace::enabled(1) {
+        for (depth, sequence) in [numbers].into_iter().chain(derivatives.iter().map(Vec::as_slice)).enumerate() {
+            let values: Vec<String> = sequence.iter().map(|e| e.to_string()).collect();
+            crate::trace!(1, "{}{}", "  ".repeat(depth), values.join(" "));
+        }
+
+        crate::trace!(1, "{} value: {}", direction, value);
+    }
+}
+
+pub fn discrete_derivatives(numbers: &[isize]) -> Vec<Vec<isize>> {
+    let mut last_derivative = numbers;
+
+    let mut discrete_derivatives = Vec::new();
+    while !last_derivative.iter().all(|e| *e == 0)
+    {
+        discrete_derivatives.push(discrete_derivative(last_derivative));
+
+        last_derivative = discrete_derivatives.last().unwrap();
+    }
+
+    discrete_derivatives
+}
+
+pub fn discrete_derivative(numbers: &[isize]) -> Vec<isize> {
+    numbers.windows(2).map(|e| e[1] - e[0]).collect()
+}
+
+// generates `size` sequences of 21 values of polynomials with a degree of at most 5
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    (0..size)
+        .map(|_| {
+            let coefficients: Vec<i64> = (0..rng.range(1..7)).map(|_| rng.range(0..21) as i64 - 10).collect();
+
+            (0..21)
+                .map(|x: i64|
+                    coefficients.iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                        .to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect()
+}
+
+// the sequences in a random order, each of them reversed or not and with a random line a + b * i added
+//
+// adding a line keeps the values on a polynomial, so they can still be extrapolated
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    let mut sequences = Day09::parse(lines)?.sequences;
+    rng.shuffle(&mut sequences);
+
+    Ok(
+        sequences.iter_mut()
+            .map(|sequence| {
+                if rng.chance(50) {
+                    sequence.reverse();
+                }
+
+                let (a, b) = (rng.range(0..41) as isize - 20, rng.range(0..41) as isize - 20);
+
+                sequence.iter()
+                    .enumerate()
+                    .map(|(i, value)| (value + a + b * i as isize).to_string())
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            })
+            .collect())
+}
+
+#[cfg(test)]
+mod test {
+    mod process {
+        use crate::day09::process;
+        use crate::day09::process_part2;
+        use crate::trace;
+
+        #[test]
+        fn it_should_extrapolate_the_next_number_in_each_sequence() {
+            assert_eq!(18, process("0 3 6 9 12 15"));
+            assert_eq!(28, process("1 3 6 10 15 21"));
+            assert_eq!(68, process("10 13 16 21 30 45"));
+        }
+
+        #[test]
+        fn it_should_extrapolate_the_number_before_the_beginning_in_each_sequence() {
+            assert_eq!(-3, process_part2("0 3 6 9 12 15"));
+            assert_eq!(0, process_part2("1 3 6 10 15 21"));
+            assert_eq!(5, process_part2("10 13 16 21 30 45"));
+        }
+
+        #[test]
+        fn it_should_trace_the_derivative_pyramid() {
+            let (_, lines) = trace::capture(1, || process("1 3 6 10 15 21"));
+
+            assert_eq!(
+                vec![
+                    "1 3 6 10 15 21",
+                    "  2 3 4 5 6",
+                    "    1 1 1 1",
+                    "      0 0 0",
+                    "next value: 28"
+                ],
+                lines
+            );
+        }
+    }
+
+    mod parse_sequence {
+        use crate::day09::parse_sequence;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_numbers() {
+            assert_eq!(Err(ParseError::new(1, 5, ParseErrorKind::InvalidNumber("3.5".to_string()))), parse_sequence("0 3 3.5 9"));
+            assert_eq!(Err(ParseError::new(1, 1, ParseErrorKind::Missing("sequence of numbers"))), parse_sequence(""));
+        }
+    }
+}
@@ -0,0 +1,586 @@
+use std::{io, collections::HashSet};
+
+use crate::Solution;
+use crate::grid::{Grid, Position};
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::render::{self, Color};
+
+pub struct Day10 {
+    pub map: Grid<char>
+}
+
+impl Solution for Day10 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let map = parse_map(lines)?;
+
+        Ok(Day10 { map })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        let farthest_distance = process(&self.map);
+
+        Ok(farthest_distance.to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        let number_of_ground_tiles_enclosed_by_pipe = process_part2(&self.map);
+
+        Ok(number_of_ground_tiles_enclosed_by_pipe.to_string())
+    }
+}
+
+pub fn parse_map(lines: &[String]) -> Result<Grid<char>, ParseError> {
+    let map = Grid::parse(lines)?;
+    let mut start_position = None;
+
+    for position in map.positions() {
+        let (x, y) = position;
+
+        match map[position] {
+            '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' => {}
+            'S' if start_position.is_none() => { start_position = Some(position); }
+            'S' => {
+                return Err(ParseError::new(y+1, x+1, ParseErrorKind::Unexpected { expected: "a single start tile", found: "S".to_string() }));
+            }
+            tile => {
+                return Err(ParseError::new(y+1, x+1, ParseErrorKind::Unexpected { expected: "one of |, -, L, J, 7, F, . or S", found: tile.to_string() }));
+            }
+        }
+    }
+
+    let Some((x, y)) = start_position else {
+        return Err(ParseError::new(map.height()+1, 1, ParseErrorKind::Missing("start tile 'S'")));
+    };
+
+    if find_loop(&map, (x, y)).is_none() {
+        return Err(ParseError::new(y+1, x+1, ParseErrorKind::Unexpected { expected: "a start tile on a closed loop", found: "S".to_string() }));
+    }
+
+    Ok(map)
+}
+
+pub fn process(map: &Grid<char>) -> usize {
+    let pipe_positions = determine_pipe_tile_positions(map);
+
+    pipe_positions.len() / 2
+}
+
+pub fn process_part2(map: &Grid<char>) -> usize {
+    enclosed_tile_positions(map).len()
+}
+
+pub fn enclosed_tile_positions(map: &Grid<char>) -> Vec<Position> {
+    let pipe_positions = determine_pipe_tile_positions(map);
+
+    let mut enclosed_tile_positions = vec![];
+    for y in 0..map.height() {
+        let mut is_inside_pipe_area = false;
+        let mut border_begin_tile = None;
+
+        for x in 0..map.width() {
+            let mut current_tile = map[(x, y)];
+            if current_tile == 'S' {
+                current_tile = guess_tile(map, (x, y)).unwrap_or(current_tile);
+            }
+
+            if pipe_positions.contains(&(x, y)) {
+                match (border_begin_tile, current_tile) {
+                    (_, 'F') => { border_begin_tile = Some('F'); }
+                    (_, 'L') => { border_begin_tile = Some('L'); }
+                    (Some('L'), '7')
+                    | (Some('F'), 'J') => { 
+                        border_begin_tile = None;
+                        is_inside_pipe_area = !is_inside_pipe_area;
+                    }
+                    (Some('F'), '7')
+                    | (Some('L'), 'J') => { border_begin_tile = None; }
+                    (_, '-') => {
+                        // continue iterating "along" the border pipe tiles
+                    }
+
+                    _ => { is_inside_pipe_area = !is_inside_pipe_area; }
+                }
+            } else if is_inside_pipe_area {
+                enclosed_tile_positions.push((x, y));
+            }
+        }
+    }
+    enclosed_tile_positions
+}
+
+// the loop with its start tile highlighted, the tiles inside and outside of it in different colors
+pub fn render(map: &Grid<char>) -> Grid<Color> {
+    let pipe_positions = determine_pipe_tile_positions(map);
+    let enclosed_tile_positions: HashSet<Position> = enclosed_tile_positions(map).into_iter().collect();
+
+    let tiles =
+        map.positions()
+            .map(|position|
+                if map[position] == 'S' {
+                    render::HIGHLIGHT
+                } else if pipe_positions.contains(&position) {
+                    render::FOREGROUND
+                } else if enclosed_tile_positions.contains(&position) {
+                    render::INSIDE
+                } else {
+                    render::SHADE
+                })
+            .collect();
+
+    Grid::new(map.width(), map.height(), tiles)
+}
+
+// empty without a closed loop through the start tile, which parse_map rules out
+pub fn determine_pipe_tile_positions(map: &Grid<char>) -> HashSet<Position> {
+    map.find(&'S')
+        .and_then(|start_position| find_loop(map, start_position))
+        .map(|positions| positions.into_iter().collect())
+        .unwrap_or_default()
+}
+
+// positions of the loop through the start tile, read as the tile guess_tile makes of it;
+// None if the pipes starting there don't lead back to it
+fn find_loop(map: &Grid<char>, start_position: Position) -> Option<Vec<Position>> {
+    let start_directions = connected_directions(guess_tile(map, start_position)?)?;
+
+    let mut positions = vec![start_position];
+    let mut position = start_position;
+    let mut direction = start_directions[0].clone();
+
+    loop {
+        position = map.neighbor(position, direction.offset())?;
+        if position == start_position {
+            return start_directions.contains(&direction.invert()).then_some(positions);
+        }
+
+        direction = follow_tile_from_direction(direction.invert(), map[position])?;
+        positions.push(position);
+
+        // a walk from the start can't run in circles elsewhere, but never rely on it
+        if positions.len() > map.width() * map.height() {
+            return None;
+        }
+    }
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+enum Direction {
+    East,
+    West,
+    North,
+    South
+}
+
+impl Direction {
+    fn invert(&self) -> Direction {
+        match self {
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+
+            Direction::North => Direction::South,
+            Direction::South => Direction::North
+        }
+    }
+
+    fn offset(&self) -> (isize, isize) {
+        match self {
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+            Direction::North => (0, -1),
+            Direction::South => (0, 1)
+        }
+    }
+}
+
+// the two sides a pipe tile connects
+fn connected_directions(tile: char) -> Option<[Direction; 2]> {
+    match tile {
+        '|' => Some([Direction::North, Direction::South]),
+        '-' => Some([Direction::East, Direction::West]),
+        'L' => Some([Direction::North, Direction::East]),
+        'J' => Some([Direction::North, Direction::West]),
+        '7' => Some([Direction::South, Direction::West]),
+        'F' => Some([Direction::South, Direction::East]),
+        _ => None
+    }
+}
+
+// the pipe tile connecting two of the adjacent tiles, if any
+pub fn guess_tile(map: &Grid<char>, position: Position) -> Option<char> {
+    let adjacent_tile = |direction: Direction| map.neighbor(position, direction.offset()).map(|adjacent_position| map[adjacent_position]);
+
+    let north = adjacent_tile(Direction::North);
+    let south = adjacent_tile(Direction::South);
+    let east = adjacent_tile(Direction::East);
+    let west = adjacent_tile(Direction::West);
+
+    match (north, east, south, west) {
+        (Some('7' | 'F' | '|'), Some('J' | '7' | '-'), _, _ ) => Some('L'),
+        (Some('7' | 'F' | '|'), _, _, Some('F' | 'L' | '-') ) => Some('J'),
+        (_, Some('J' | '7' | '-'), Some('J' | 'L' | '|'), _) => Some('F'),
+        (_, _, Some('J' | 'L' | '|'), Some('F' | 'L' | '-')) => Some('7'),
+        (Some('7' | 'F' | '|'), _, Some('J' | 'L' | '|'), _) => Some('|'),
+        (_, Some('J' | '7' | '-'), _, Some('F' | 'L' | '-')) => Some('-'),
+        _ => None
+    }
+}
+
+// the side a pipe tile leads to when entered from the other one, None if it doesn't connect that side
+fn follow_tile_from_direction(from: Direction, tile: char) -> Option<Direction> {
+    match (from, tile) {
+        (Direction::South, 'F') => Some(Direction::East),
+        (Direction::East, 'F')  => Some(Direction::South),
+        (Direction::West, '7')  => Some(Direction::South),
+        (Direction::South, '7') => Some(Direction::West),
+        (Direction::North, 'J') => Some(Direction::West),
+        (Direction::West, 'J')  => Some(Direction::North),
+        (Direction::North, 'L') => Some(Direction::East),
+        (Direction::East, 'L')  => Some(Direction::North),
+        (Direction::East, '-') => Some(Direction::West),
+        (Direction::West, '-')  => Some(Direction::East),
+        (Direction::North, '|') => Some(Direction::South),
+        (Direction::South, '|')  => Some(Direction::North),
+
+        _ => None
+    }
+}
+
+// generates a map of `size` x `size` tiles with a loop enclosing ground tiles
+//
+// the loop runs around a random spanning tree of blocks of 2x2 cells, which
+// gives a loop visiting every cell of these blocks, then the loop is scaled
+// by 2 so there is room for tiles inside of it
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    const NORTH: u8 = 1;
+    const EAST: u8 = 2;
+    const SOUTH: u8 = 4;
+    const WEST: u8 = 8;
+    const JUNK: [char; 9] = ['.', '.', '.', '|', '-', 'L', 'J', '7', 'F'];
+
+    let size = size.max(4);
+    let blocks = size / 4;
+
+    // grow a random tree of blocks
+    let mut in_tree = Grid::filled(blocks, blocks, false);
+    let start = (rng.index(blocks), rng.index(blocks));
+    in_tree[start] = true;
+
+    let target = (blocks * blocks * rng.range(50..101) as usize / 100).max(1);
+    let mut tree_size = 1;
+    let mut tree_edges = vec![];
+    let mut frontier: Vec<(Position, Position)> = in_tree.neighbors4(start).map(|next| (start, next)).collect();
+
+    while tree_size < target && !frontier.is_empty() {
+        let (from, to) = frontier.swap_remove(rng.index(frontier.len()));
+        if in_tree[to] {
+            continue;
+        }
+
+        in_tree[to] = true;
+        tree_size += 1;
+        tree_edges.push((from, to));
+        frontier.extend(in_tree.neighbors4(to).filter(|next| !in_tree[*next]).map(|next| (to, next)));
+    }
+
+    // a ring of 4 cells per block, rings of connected blocks are merged
+    let mut connections: Grid<u8> = Grid::filled(2 * blocks, 2 * blocks, 0);
+    for (x, y) in in_tree.find_all(&true) {
+        connections[(2 * x, 2 * y)] = EAST | SOUTH;
+        connections[(2 * x + 1, 2 * y)] = WEST | SOUTH;
+        connections[(2 * x, 2 * y + 1)] = NORTH | EAST;
+        connections[(2 * x + 1, 2 * y + 1)] = NORTH | WEST;
+    }
+
+    let mut reconnect = |position: Position, removed: u8, added: u8| {
+        connections[position] = connections[position] & !removed | added;
+    };
+
+    for (from, to) in tree_edges {
+        let ((ax, ay), (bx, by)) = if from < to { (from, to) } else { (to, from) };
+
+        if ay == by {
+            reconnect((2 * ax + 1, 2 * ay), SOUTH, EAST);
+            reconnect((2 * ax + 1, 2 * ay + 1), NORTH, EAST);
+            reconnect((2 * bx, 2 * by), SOUTH, WEST);
+            reconnect((2 * bx, 2 * by + 1), NORTH, WEST);
+        } else {
+            reconnect((2 * ax, 2 * ay + 1), EAST, SOUTH);
+            reconnect((2 * ax + 1, 2 * ay + 1), WEST, SOUTH);
+            reconnect((2 * bx, 2 * by), EAST, NORTH);
+            reconnect((2 * bx + 1, 2 * by), WEST, NORTH);
+        }
+    }
+
+    // scale the loop into the map
+    let mut map = Grid::filled(size, size, ' ');
+    let (offset_x, offset_y) = (rng.index(size - 4 * blocks + 2), rng.index(size - 4 * blocks + 2));
+    let mut corners = vec![];
+
+    for (x, y) in connections.positions() {
+        let position = (2 * x + offset_x, 2 * y + offset_y);
+
+        map[position] =
+            match connections[(x, y)] {
+                0 => continue,
+                connection if connection == NORTH | SOUTH => '|',
+                connection if connection == EAST | WEST => '-',
+                connection if connection == NORTH | EAST => 'L',
+                connection if connection == NORTH | WEST => 'J',
+                connection if connection == SOUTH | WEST => '7',
+                _ => 'F'
+            };
+
+        if !"|-".contains(map[position]) {
+            corners.push(position);
+        }
+        if connections[(x, y)] & EAST != 0 {
+            map[(position.0 + 1, position.1)] = '-';
+        }
+        if connections[(x, y)] & SOUTH != 0 {
+            map[(position.0, position.1 + 1)] = '|';
+        }
+    }
+
+    // the start tile is a corner, so its neighbors tell which corner it is
+    let start = *rng.pick(&corners);
+    map[start] = 'S';
+    for neighbor in map.neighbors4(start).collect::<Vec<Position>>() {
+        if map[neighbor] == ' ' {
+            map[neighbor] = '.';
+        }
+    }
+
+    for position in map.find_all(&' ').collect::<Vec<Position>>() {
+        map[position] = *rng.pick(&JUNK);
+    }
+
+    map.rows().map(|row| row.iter().collect()).collect()
+}
+
+// the map mirrored and transposed at random, with the tiles off the loop replaced with random tiles
+//
+// the tiles next to the start tile become ground, so they can't be mistaken for a part of the loop
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    const TILES: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+    let map = parse_map(lines)?;
+    let pipe_positions = determine_pipe_tile_positions(&map);
+    let start_position = map.find(&'S').expect("the map was parsed");
+    let next_to_start: Vec<Position> = map.neighbors4(start_position).collect();
+
+    let (mirror_x, mirror_y, transpose) = (rng.chance(50), rng.chance(50), rng.chance(50));
+    let turn = |tile: char| {
+        let tile = if mirror_x { match tile { 'L' => 'J', 'J' => 'L', 'F' => '7', '7' => 'F', tile => tile } } else { tile };
+        let tile = if mirror_y { match tile { 'L' => 'F', 'F' => 'L', 'J' => '7', '7' => 'J', tile => tile } } else { tile };
+
+        if transpose { match tile { '|' => '-', '-' => '|', 'L' => '7', '7' => 'L', tile => tile } } else { tile }
+    };
+
+    let (width, height) = if transpose { (map.height(), map.width()) } else { (map.width(), map.height()) };
+
+    Ok(
+        (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        // position of the tile in the given map
+                        let (x, y) = if transpose { (y, x) } else { (x, y) };
+                        let x = if mirror_x { map.width() - 1 - x } else { x };
+                        let y = if mirror_y { map.height() - 1 - y } else { y };
+
+                        if pipe_positions.contains(&(x, y)) {
+                            turn(map[(x, y)])
+                        } else if next_to_start.contains(&(x, y)) {
+                            '.'
+                        } else {
+                            *rng.pick(&TILES)
+                        }
+                    })
+                    .collect()
+            })
+            .collect())
+}
+
+#[cfg(test)]
+mod test {
+    mod process {
+        use crate::day10::process;
+        use crate::day10::process_part2;
+        use crate::grid::Grid;
+
+        fn map(input: &str) -> Grid<char> {
+            Grid::parse(&input.split("\n").map(|e| e.to_string()).collect::<Vec<String>>()).unwrap()
+        }
+
+        const SAMPLE_INPUT_1: &str =
+".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+
+        const SAMPLE_INPUT_2: &str =
+"..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+        const SAMPLE_INPUT_3: &str = 
+"...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+        const SAMPLE_INPUT_4: &str =
+".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+        const SAMPLE_INPUT_5: &str =
+"FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+        #[test]
+        fn it_should_calculate_the_distance_to_the_farthest_point() {
+            assert_eq!(
+                4, 
+                
+                process(&map(SAMPLE_INPUT_1)));
+            assert_eq!(
+                8,
+
+                process(&map(SAMPLE_INPUT_2)));
+        }
+
+        #[test]
+        fn it_should_calculate_the_number_of_enclosed_ground_tiles() {
+            assert_eq!(
+                1, 
+                
+                process_part2(&map(SAMPLE_INPUT_1)));
+            assert_eq!(
+                1,
+
+                process_part2(&map(SAMPLE_INPUT_2)));
+
+            assert_eq!(
+                4,
+
+                process_part2(&map(SAMPLE_INPUT_3)));
+
+            assert_eq!(
+                8,
+
+                process_part2(&map(SAMPLE_INPUT_4)));
+
+            assert_eq!(
+                10,
+
+                process_part2(&map(SAMPLE_INPUT_5)));
+        }
+    }
+
+    mod render {
+        use crate::day10::render;
+        use crate::grid::Grid;
+        use crate::render::{FOREGROUND as L, HIGHLIGHT as S, INSIDE as I, SHADE as O};
+
+        #[test]
+        fn it_should_color_the_loop_and_the_tiles_inside_and_outside() {
+            let map = Grid::parse(&["S-7.", "|.|.", "L-J-"].map(|line| line.to_string())).unwrap();
+
+            assert_eq!(
+                Grid::from_rows(vec![
+                    vec![S, L, L, O],
+                    vec![L, I, L, O],
+                    vec![L, L, L, O]
+                ]).unwrap(),
+
+                render(&map)
+            );
+        }
+    }
+
+    mod parse_map {
+        use crate::day10::parse_map;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        fn lines(input: &str) -> Vec<String> {
+            input.split("\n").map(|e| e.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_tiles() {
+            assert_eq!(
+                Err(ParseError::new(2, 4, ParseErrorKind::Unexpected { expected: "one of |, -, L, J, 7, F, . or S", found: "X".to_string() })),
+                parse_map(&lines(".....\n.S-X.\n.|.|.\n.L-J.\n....."))
+            );
+
+            assert_eq!(
+                Err(ParseError::new(3, 3, ParseErrorKind::InvalidLength { expected: 5, found: 2 })),
+                parse_map(&lines(".....\n.S-7.\n.|"))
+            );
+
+            assert_eq!(
+                Err(ParseError::new(2, 1, ParseErrorKind::Missing("start tile 'S'"))),
+                parse_map(&lines("....."))
+            );
+        }
+
+        #[test]
+        fn it_should_report_start_tiles_not_on_a_closed_loop() {
+            let error = ParseErrorKind::Unexpected { expected: "a start tile on a closed loop", found: "S".to_string() };
+
+            assert_eq!(Err(ParseError::new(1, 1, error.clone())), parse_map(&lines("S.\n..")));
+            assert_eq!(Err(ParseError::new(2, 2, error.clone())), parse_map(&lines(".....\n.S-7.\n.|.|.\n.L-..\n.....")));
+            assert_eq!(Err(ParseError::new(1, 1, error)), parse_map(&lines("S-7\n|.|\nL--")));
+
+            assert!(parse_map(&lines(".....\n.S-7.\n.|.|.\n.L-J.\n.....")).is_ok());
+        }
+    }
+
+    mod anonymize {
+        use crate::day10::{anonymize, parse_map, process, process_part2};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_keep_the_loop_and_the_tiles_inside() {
+            let lines: Vec<String> =
+                ["..........", ".S------7.", ".|F----7|.", ".||-J..||.", ".||....||.", ".|L-7F-J|.", ".|..||..|.", ".L--JL--J.", ".........."]
+                    .map(|line| line.to_string()).to_vec();
+
+            let map = parse_map(&lines).unwrap();
+
+            for seed in 0..8 {
+                let anonymized = parse_map(&anonymize(&mut Rng::new(seed), &lines).unwrap()).unwrap();
+
+                assert_eq!(process(&map), process(&anonymized));
+                assert_eq!(4, process_part2(&anonymized));
+            }
+        }
+    }
+}
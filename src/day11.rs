@@ -0,0 +1,307 @@
+use std::io;
+
+use crate::Solution;
+use crate::grid::{Grid, Position};
+use crate::parse_error::{ParseError, ParseErrorKind};
+use crate::random::Rng;
+use crate::shell::{self, Command, Commands};
+use crate::render::{self, Color};
+
+pub struct Day11 {
+    pub universe: Grid<char>
+}
+
+impl Solution for Day11 {
+    fn parse(lines: &[String]) -> Result<Self, ParseError> {
+        let universe = parse_universe(lines)?;
+
+        Ok(Day11 { universe })
+    }
+
+    fn part1(&self) -> io::Result<String> {
+        Ok(process(&self.universe, 2).to_string())
+    }
+
+    fn part2(&self) -> io::Result<String> {
+        Ok(process(&self.universe, 1_000_000).to_string())
+    }
+}
+
+impl Commands for Day11 {
+    const COMMANDS: &'static [Command<Day11>] = &[
+        Command { name: "distance", arguments: "<galaxy> <galaxy>", help: "distance between the galaxies, numbered from 1 in reading order", run: distance_command }
+    ];
+}
+
+// "distance 5 9" prints where the galaxies are and their distance with the expansion of each part
+fn distance_command(day: &Day11, arguments: &[&str]) -> Result<String, String> {
+    let galaxies = location_of_galaxies(&day.universe);
+
+    let galaxy = |argument: &str| {
+        let number: usize = shell::parse_argument(argument, "galaxy")?;
+
+        number.checked_sub(1)
+            .and_then(|index| galaxies.get(index))
+            .map(|position| (number, *position))
+            .ok_or(format!("no galaxy {}, there are {}", number, galaxies.len()))
+    };
+
+    let [g1, g2] = shell::arguments(arguments)?;
+    let ((n1, g1), (n2, g2)) = (galaxy(g1)?, galaxy(g2)?);
+
+    let rows_to_expand = get_rows_to_expand(&day.universe);
+    let columns_to_expand = get_columns_to_expand(&day.universe);
+
+    Ok(format!(
+        "galaxy {} at line {}, column {} and galaxy {} at line {}, column {}\npart 1: {}\npart 2: {}",
+        n1, g1.1+1, g1.0+1, n2, g2.1+1, g2.0+1,
+        distance(g1, g2, &rows_to_expand, &columns_to_expand, 2),
+        distance(g1, g2, &rows_to_expand, &columns_to_expand, 1_000_000)))
+}
+
+pub fn parse_universe(lines: &[String]) -> Result<Grid<char>, ParseError> {
+    let universe = Grid::parse(lines)?;
+
+    if let Some((x, y)) = universe.positions().find(|position| universe[*position] != '.' && universe[*position] != '#') {
+        return Err(ParseError::new(y+1, x+1, ParseErrorKind::Unexpected { expected: ". or #", found: universe[(x, y)].to_string() }));
+    }
+
+    Ok(universe)
+}
+
+pub fn process(universe: &Grid<char>, expand_times: usize) -> usize {
+    let galaxies = location_of_galaxies(universe);
+
+    let rows_to_expand = get_rows_to_expand(universe);
+    let columns_to_expand = get_columns_to_expand(universe);
+
+    galaxies.iter().enumerate()
+        .flat_map(|(index_g1, g1)|
+
+        galaxies.iter().enumerate()
+            .filter(|(index_g2, _)| index_g1 < *index_g2)
+            .map(|(_, g2)| distance(*g1, *g2, &rows_to_expand, &columns_to_expand, expand_times) )
+            .collect::<Vec<usize>>())
+        .sum::<usize>()
+}
+
+pub fn get_rows_to_expand(universe: &Grid<char>) -> Vec<usize> {
+    universe.rows().enumerate()
+        .filter(|(_, row)| row.iter().all(|e| *e == '.'))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+pub fn get_columns_to_expand(universe: &Grid<char>) -> Vec<usize> {
+    universe.columns().enumerate()
+        .filter_map(|(index, mut column)| column.all(|e| *e == '.').then_some(index))
+        .collect()
+}
+
+pub fn location_of_galaxies(universe: &Grid<char>) -> Vec<Position> {
+    universe.find_all(&'#').collect()
+}
+
+pub fn distance(g1: Position, g2: Position, rows_to_expand: &[usize], columns_to_expand: &[usize], expand_times: usize) -> usize {
+    let vertical_distance = g2.1.abs_diff(g1.1);
+    let horizontal_distance = g2.0.abs_diff(g1.0);
+
+    let vertical_expansion: usize =
+        rows_to_expand.iter()
+            .filter(|row| if g2.1 > g1.1 { **row > g1.1 } else { **row > g2.1 })
+            .filter(|row| if g2.1 > g1.1 { **row < g2.1 } else { **row < g1.1 })
+            .map(|_| expand_times-1)
+            .sum();
+
+    let horizontal_expansion: usize =
+        columns_to_expand.iter()
+            .filter(|column| if g2.0 > g1.0 { **column > g1.0 } else { **column > g2.0 })
+            .filter(|column| if g2.0 > g1.0 { **column < g2.0 } else { **column < g1.0 })
+            .map(|_| expand_times-1)
+            .sum();
+
+    vertical_distance + vertical_expansion + horizontal_distance + horizontal_expansion
+}
+
+// galaxies on the background, the rows and columns to expand shaded
+pub fn render(universe: &Grid<char>) -> Grid<Color> {
+    let rows_to_expand = get_rows_to_expand(universe);
+    let columns_to_expand = get_columns_to_expand(universe);
+
+    let tiles =
+        universe.positions()
+            .map(|(x, y)|
+                if universe[(x, y)] == '#' {
+                    render::FOREGROUND
+                } else if rows_to_expand.contains(&y) || columns_to_expand.contains(&x) {
+                    render::SHADE
+                } else {
+                    render::BACKGROUND
+                })
+            .collect();
+
+    Grid::new(universe.width(), universe.height(), tiles)
+}
+
+// generates a universe with `size` galaxies, about one in ten rows and columns stays empty
+pub fn generate(rng: &mut Rng, size: usize) -> Vec<String> {
+    let size = size.max(1);
+    let side = ((size * 10) as f64).sqrt().ceil() as usize;
+
+    let rows: Vec<usize> = (0..side).filter(|_| !rng.chance(10)).collect();
+    let columns: Vec<usize> = (0..side).filter(|_| !rng.chance(10)).collect();
+
+    // the remaining rows and columns still leave room for all galaxies
+    let (rows, columns) =
+        if rows.len() * columns.len() < size {
+            ((0..side).collect(), (0..side).collect())
+        } else {
+            (rows, columns)
+        };
+
+    let mut universe = Grid::filled(side, side, '.');
+    let mut galaxies = 0;
+    while galaxies < size {
+        let position = (*rng.pick(&columns), *rng.pick(&rows));
+
+        if universe[position] == '.' {
+            universe[position] = '#';
+            galaxies += 1;
+        }
+    }
+
+    universe.rows().map(|row| row.iter().collect()).collect()
+}
+
+// the rows and the columns of the image in a random order
+//
+// empty rows and columns stay empty, only the distances between the galaxies change
+pub fn anonymize(rng: &mut Rng, lines: &[String]) -> Result<Vec<String>, ParseError> {
+    let universe = parse_universe(lines)?;
+
+    let mut rows: Vec<usize> = (0..universe.height()).collect();
+    let mut columns: Vec<usize> = (0..universe.width()).collect();
+    rng.shuffle(&mut rows);
+    rng.shuffle(&mut columns);
+
+    Ok(
+        rows.iter()
+            .map(|y| columns.iter().map(|x| universe[(*x, *y)]).collect())
+            .collect())
+}
+
+#[cfg(test)]
+mod test {
+    mod process {
+        use crate::day11::process;
+        use crate::grid::Grid;
+
+        fn universe(input: &str) -> Grid<char> {
+            Grid::parse(&input.lines().map(|e| e.to_string()).collect::<Vec<String>>()).unwrap()
+        }
+
+        const SAMPLE_INPUT_1: &str =
+"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+        #[test]
+        fn it_should_sum_up_all_pairwise_shortest_distances_in_expanded_universe() {
+            assert_eq!(
+                374,
+            
+                process(&universe(SAMPLE_INPUT_1), 2));
+
+            assert_eq!(
+                1030,
+            
+                process(&universe(SAMPLE_INPUT_1), 10));
+
+            assert_eq!(
+                8410,
+            
+                process(&universe(SAMPLE_INPUT_1), 100));
+        }
+    }
+
+    mod render {
+        use crate::day11::render;
+        use crate::grid::Grid;
+        use crate::render::{BACKGROUND as B, FOREGROUND as G, SHADE as E};
+
+        #[test]
+        fn it_should_shade_the_rows_and_columns_to_expand() {
+            let universe = Grid::parse(&["#..", "...", "..#"].map(|line| line.to_string())).unwrap();
+
+            assert_eq!(
+                Grid::from_rows(vec![
+                    vec![G, E, B],
+                    vec![E, E, E],
+                    vec![B, E, G]
+                ]).unwrap(),
+
+                render(&universe)
+            );
+        }
+    }
+
+    mod distance_command {
+        use crate::Solution;
+        use crate::day11::Day11;
+        use crate::shell::Shell;
+
+        #[test]
+        fn it_should_tell_the_distance_between_two_galaxies() {
+            let day = Day11::parse(&["...#".to_string(), "....".to_string(), "#...".to_string()]).unwrap();
+
+            assert_eq!(
+                Ok("galaxy 1 at line 1, column 4 and galaxy 2 at line 3, column 1\npart 1: 8\npart 2: 3000002".to_string()),
+                day.execute("distance 1 2")
+            );
+            assert!(day.execute("distance 1 3").unwrap_err().starts_with("no galaxy 3, there are 2"));
+        }
+    }
+
+    mod parse_universe {
+        use crate::day11::parse_universe;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_should_report_the_position_of_invalid_tiles() {
+            assert_eq!(
+                Err(ParseError::new(2, 3, ParseErrorKind::Unexpected { expected: ". or #", found: "*".to_string() })),
+                parse_universe(&["...#".to_string(), "..*.".to_string()])
+            );
+
+            assert_eq!(
+                Err(ParseError::new(2, 3, ParseErrorKind::InvalidLength { expected: 4, found: 2 })),
+                parse_universe(&["...#".to_string(), "..".to_string()])
+            );
+        }
+    }
+
+    mod anonymize {
+        use crate::day11::{anonymize, get_columns_to_expand, get_rows_to_expand, location_of_galaxies, parse_universe};
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_shuffle_rows_and_columns() {
+            let lines: Vec<String> = ["...#......", ".......#..", "#.........", "..........", "......#...", ".#........", ".........#", "..........", ".......#..", "#...#....."]
+                .map(|line| line.to_string()).to_vec();
+
+            let universe = parse_universe(&lines).unwrap();
+            let anonymized = parse_universe(&anonymize(&mut Rng::new(2), &lines).unwrap()).unwrap();
+
+            assert_eq!(location_of_galaxies(&universe).len(), location_of_galaxies(&anonymized).len());
+            assert_eq!(get_rows_to_expand(&universe).len(), get_rows_to_expand(&anonymized).len());
+            assert_eq!(get_columns_to_expand(&universe).len(), get_columns_to_expand(&anonymized).len());
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::input_helper;
+use crate::parse_error::{ParseError, ParseErrorKind};
+
+// (x, y) with x counting columns from the left and y counting rows from the top
+pub type Position = (usize, usize);
+
+// offsets of the 4-connected neighbors: north, west, east, south
+const NEIGHBORS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+// offsets of the 8-connected neighbors in reading order
+const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0),           (1, 0),
+    (-1, 1),  (0, 1),  (1, 1)
+];
+
+// Rectangular 2D map stored row by row
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl Grid<char> {
+    // parses a character map, all lines must have the same length
+    pub fn parse(lines: &[String]) -> Result<Grid<char>, ParseError> {
+        input_helper::validate_rectangular(lines)?;
+
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        Ok(Grid::new(width, lines.len(), lines.iter().flat_map(|line| line.chars()).collect()))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(width * height, cells.len(), "number of cells does not match the grid size");
+
+        Grid { width, height, cells }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, ParseError> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::new(y+1, row.len().min(width) + 1, ParseErrorKind::InvalidLength { expected: width, found: row.len() }));
+            }
+
+            cells.extend(row);
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.0 < self.width && position.1 < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.1 * self.width + position.0])
+        } else {
+            None
+        }
+    }
+
+    // position next to the given one, None if it lies outside of the grid
+    pub fn neighbor(&self, position: Position, offset: (isize, isize)) -> Option<Position> {
+        let x = position.0.checked_add_signed(offset.0)?;
+        let y = position.1.checked_add_signed(offset.1)?;
+
+        if self.contains((x, y)) {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    // horizontally and vertically adjacent positions within the grid
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBORS_4.iter()
+            .filter_map(move |offset| self.neighbor(position, *offset))
+    }
+
+    // horizontally, vertically and diagonally adjacent positions within the grid, in reading order
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBORS_8.iter()
+            .filter_map(move |offset| self.neighbor(position, *offset))
+    }
+
+    // all positions in reading order
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y+1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} out of range", x);
+
+        (0..self.height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq
+    {
+        self.positions()
+            .filter(move |position| self[*position] == *value)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect()
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {:?} out of range for grid of size {}x{}", position, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {:?} out of range for grid of size {}x{}", position, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::grid::Grid;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(&["abc".to_string(), "def".to_string()]).unwrap()
+    }
+
+    mod parse {
+        use crate::grid::Grid;
+        use crate::grid::test::sample;
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_should_parse_a_rectangular_map() {
+            let grid = sample();
+
+            assert_eq!(3, grid.width());
+            assert_eq!(2, grid.height());
+            assert_eq!('a', grid[(0, 0)]);
+            assert_eq!('f', grid[(2, 1)]);
+        }
+
+        #[test]
+        fn it_should_reject_rows_of_different_length() {
+            assert_eq!(
+                Err(ParseError::new(2, 3, ParseErrorKind::InvalidLength { expected: 3, found: 2 })),
+                Grid::parse(&["abc".to_string(), "de".to_string()])
+            );
+
+            assert_eq!(
+                Err(ParseError::new(2, 4, ParseErrorKind::InvalidLength { expected: 3, found: 4 })),
+                Grid::parse(&["abc".to_string(), "defg".to_string()])
+            );
+        }
+
+        #[test]
+        fn it_should_display_the_map_as_text() {
+            assert_eq!("abc\ndef", sample().to_string());
+        }
+    }
+
+    mod access {
+        use crate::grid::test::sample;
+
+        #[test]
+        fn it_should_return_none_outside_of_the_grid() {
+            let grid = sample();
+
+            assert_eq!(Some(&'e'), grid.get((1, 1)));
+            assert_eq!(None, grid.get((3, 0)));
+            assert_eq!(None, grid.get((0, 2)));
+        }
+
+        #[test]
+        fn it_should_modify_cells() {
+            let mut grid = sample();
+            grid[(1, 0)] = 'x';
+
+            assert_eq!("axc\ndef", grid.to_string());
+        }
+    }
+
+    mod neighbors {
+        use crate::grid::test::sample;
+
+        #[test]
+        fn it_should_only_return_neighbors_within_the_grid() {
+            let grid = sample();
+
+            assert_eq!(vec![(1, 0), (0, 1)], grid.neighbors4((0, 0)).collect::<Vec<_>>());
+            assert_eq!(vec![(1, 0), (0, 1), (2, 1)], grid.neighbors4((1, 1)).collect::<Vec<_>>());
+            assert_eq!(vec![(1, 0), (0, 1), (1, 1)], grid.neighbors8((0, 0)).collect::<Vec<_>>());
+            assert_eq!(
+                vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)],
+                grid.neighbors8((1, 1)).collect::<Vec<_>>()
+            );
+        }
+
+        #[test]
+        fn it_should_return_a_single_neighbor_by_offset() {
+            let grid = sample();
+
+            assert_eq!(Some((2, 1)), grid.neighbor((1, 0), (1, 1)));
+            assert_eq!(None, grid.neighbor((0, 0), (-1, 0)));
+            assert_eq!(None, grid.neighbor((2, 1), (1, 0)));
+        }
+    }
+
+    mod rows_and_columns {
+        use crate::grid::test::sample;
+
+        #[test]
+        fn it_should_iterate_rows_and_columns() {
+            let grid = sample();
+
+            assert_eq!(
+                vec!["abc".to_string(), "def".to_string()],
+                grid.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>()
+            );
+
+            assert_eq!(
+                vec!["ad".to_string(), "be".to_string(), "cf".to_string()],
+                grid.columns().map(|column| column.collect::<String>()).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    mod find {
+        use crate::grid::Grid;
+
+        #[test]
+        fn it_should_find_cells_by_value() {
+            let grid = Grid::parse(&["#..".to_string(), ".#.".to_string()]).unwrap();
+
+            assert_eq!(Some((0, 0)), grid.find(&'#'));
+            assert_eq!(vec![(0, 0), (1, 1)], grid.find_all(&'#').collect::<Vec<_>>());
+            assert_eq!(None, grid.find(&'S'));
+        }
+    }
+
+    #[test]
+    fn it_should_map_cells() {
+        assert_eq!("bcd\nefg", sample().map(|c| (*c as u8 + 1) as char).to_string());
+    }
+}
@@ -0,0 +1,271 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use crate::input_helper::Input;
+use crate::json::Json;
+use crate::pool::{self, Task};
+use crate::runner::{self, Run, DAYS};
+
+// Minimal HTTP/1.1 server answering puzzle inputs with JSON
+//
+// GET /days lists the available days, POST /day/<n> solves the input sent as body;
+// every response closes the connection
+
+pub const DEFAULT_PORT: u16 = 8023;
+
+// inputs are a few KB, anything far beyond is not a puzzle input
+const MAX_BODY_SIZE: usize = 10 * 1024 * 1024;
+const MAX_HEADER_SIZE: usize = 64 * 1024;
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Json
+}
+
+impl Request {
+    // value of the header, names are case-insensitive
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Response {
+    pub fn ok(body: Json) -> Response {
+        Response { status: 200, body }
+    }
+
+    pub fn error(status: u16, message: &str) -> Response {
+        Response { status, body: Json::object(vec![("error", Json::string(message))]) }
+    }
+
+    pub fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Content Too Large",
+            _ => "Internal Server Error"
+        }
+    }
+
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        let body = format!("{}\n", self.body);
+
+        write!(writer, "HTTP/1.1 {} {}\r\n", self.status, self.reason())?;
+        write!(writer, "Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len())?;
+        writer.write_all(body.as_bytes())?;
+        writer.flush()
+    }
+}
+
+// reads the request line, the headers and a body of the given Content-Length
+//
+// a malformed request is answered with the error response
+pub fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let mut header_reader = reader.take(MAX_HEADER_SIZE as u64);
+    let mut read_line = || {
+        let mut line = String::new();
+        match header_reader.read_line(&mut line) {
+            Ok(0) => Err(Response::error(400, "incomplete request")),
+            Ok(_) if !line.ends_with('\n') => Err(Response::error(400, "request header too large")),
+            Ok(_) => Ok(line.trim_end_matches(['\r', '\n']).to_string()),
+            Err(err) => Err(Response::error(400, &err.to_string()))
+        }
+    };
+
+    let request_line = read_line()?;
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(path), Some(version), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+        return Err(Response::error(400, &format!("invalid request line: '{}'", request_line)));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(Response::error(400, &format!("unsupported version: {}", version)));
+    }
+
+    let mut headers = vec![];
+    loop {
+        let line = read_line()?;
+        if line.is_empty() {
+            break;
+        }
+
+        let (name, value) = line.split_once(':').ok_or(Response::error(400, &format!("invalid header: '{}'", line)))?;
+        headers.push((name.trim().to_string(), value.trim().to_string()));
+    }
+
+    let mut request = Request { method: method.to_string(), path: path.to_string(), headers, body: vec![] };
+
+    if request.header("Transfer-Encoding").is_some() {
+        return Err(Response::error(411, "chunked bodies are not supported, send a Content-Length"));
+    }
+
+    if let Some(length) = request.header("Content-Length") {
+        let length: usize = length.parse().map_err(|_| Response::error(400, &format!("invalid Content-Length: '{}'", length)))?;
+        if length > MAX_BODY_SIZE {
+            return Err(Response::error(413, &format!("body exceeds {} bytes", MAX_BODY_SIZE)));
+        }
+
+        request.body = vec![0; length];
+        reader.read_exact(&mut request.body).map_err(|_| Response::error(400, "body shorter than its Content-Length"))?;
+    } else if request.method == "POST" {
+        return Err(Response::error(411, "missing Content-Length"));
+    }
+
+    Ok(request)
+}
+
+pub fn route(request: &Request) -> Response {
+    let path = request.path.split('?').next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+
+    match (request.method.as_str(), segments.as_slice()) {
+        ("GET", ["days"]) => list_days(),
+        ("POST", ["day", number]) => solve_day(number, &request.body),
+        (_, ["days"]) | (_, ["day", _]) => Response::error(405, &format!("{} is not allowed on {}", request.method, path)),
+        _ => Response::error(404, &format!("no route for {}", path))
+    }
+}
+
+fn list_days() -> Response {
+    let days: Vec<Json> = DAYS.iter().map(|day| Json::Number(day.number as i64)).collect();
+
+    Response::ok(Json::object(vec![("days", Json::Array(days))]))
+}
+
+fn solve_day(number: &str, body: &[u8]) -> Response {
+    let Some(day) = number.parse::<u8>().ok().and_then(runner::find_day) else {
+        return Response::error(404, &format!("no solution for day {}", number));
+    };
+
+    let Ok(text) = std::str::from_utf8(body) else {
+        return Response::error(400, "the input is not valid UTF-8");
+    };
+
+    // a panicking day fails the request instead of dropping the connection
+    let input = Input::Text(text.to_string());
+    let task: Task<Run> = Box::new(|| day.run_timed(&input));
+
+    match pool::run(1, vec![task]).remove(0) {
+        Ok(run) => Response::ok(runner::run_to_json(&run)),
+        Err(message) => Response::error(500, &format!("day {} panicked: {}", day.number, message))
+    }
+}
+
+pub fn handle_connection(stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut reader = BufReader::new(&stream);
+    let response = read_request(&mut reader).map_or_else(|response| response, |request| route(&request));
+
+    response.write_to(&mut &stream)
+}
+
+// answers each connection on its own thread, until accepting fails
+pub fn serve(listener: TcpListener) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+
+        thread::spawn(move || {
+            if let Err(err) = handle_connection(stream) {
+                eprintln!("{}", err);
+            }
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    mod read_request {
+        use crate::http::{read_request, Request, Response};
+
+        #[test]
+        fn it_should_read_headers_and_body() {
+            let raw = "POST /day/9 HTTP/1.1\r\nHost: localhost\r\ncontent-length: 5\r\n\r\n0 3 6extra";
+
+            assert_eq!(
+                Ok(Request {
+                    method: "POST".to_string(),
+                    path: "/day/9".to_string(),
+                    headers: vec![("Host".to_string(), "localhost".to_string()), ("content-length".to_string(), "5".to_string())],
+                    body: b"0 3 6".to_vec()
+                }),
+
+                read_request(&mut raw.as_bytes())
+            );
+        }
+
+        #[test]
+        fn it_should_reject_malformed_requests() {
+            let status = |raw: &str| read_request(&mut raw.as_bytes()).map_err(|response: Response| response.status).err();
+
+            assert_eq!(Some(400), status("GET /days\r\n\r\n"));
+            assert_eq!(Some(400), status("GET /days HTTP/2\r\n\r\n"));
+            assert_eq!(Some(400), status("GET /days HTTP/1.1\r\nno colon\r\n\r\n"));
+            assert_eq!(Some(400), status("GET /days HTTP/1.1\r\n"));
+            assert_eq!(Some(400), status("POST /day/1 HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort"));
+            assert_eq!(Some(411), status("POST /day/1 HTTP/1.1\r\n\r\n"));
+            assert_eq!(Some(413), status("POST /day/1 HTTP/1.1\r\nContent-Length: 999999999\r\n\r\n"));
+            assert_eq!(None, status("GET /days HTTP/1.0\r\n\r\n"));
+        }
+    }
+
+    mod route {
+        use crate::http::{route, Request};
+        use crate::json::Json;
+
+        fn request(method: &str, path: &str, body: &str) -> Request {
+            Request { method: method.to_string(), path: path.to_string(), headers: vec![], body: body.as_bytes().to_vec() }
+        }
+
+        #[test]
+        fn it_should_list_the_days() {
+            let response = route(&request("GET", "/days", ""));
+
+            assert_eq!(200, response.status);
+            assert_eq!("{\"days\":[1,2,3,4,5,6,7,8,9,10,11]}", response.body.to_string());
+        }
+
+        #[test]
+        fn it_should_solve_the_posted_input() {
+            let response = route(&request("POST", "/day/09", "0 3 6 9 12 15\n"));
+
+            assert_eq!(200, response.status);
+            let Json::Object(members) = response.body else { panic!("not an object") };
+            assert_eq!(Some(&Json::Number(9)), members.iter().find(|(key, _)| key == "day").map(|(_, value)| value));
+            assert_eq!(
+                Some(&Json::object(vec![("answer", Json::string("18"))])),
+                members.iter().find(|(key, _)| key == "part1").map(|(_, value)| value)
+            );
+        }
+
+        #[test]
+        fn it_should_answer_unknown_routes_and_methods_with_errors() {
+            assert_eq!(404, route(&request("POST", "/day/26", "")).status);
+            assert_eq!(404, route(&request("GET", "/", "")).status);
+            assert_eq!(405, route(&request("GET", "/day/1", "")).status);
+            assert_eq!(405, route(&request("DELETE", "/days", "")).status);
+            assert_eq!(400, route(&Request { body: vec![0xff, 0xfe], ..request("POST", "/day/1", "") }).status);
+        }
+    }
+}
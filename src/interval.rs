@@ -0,0 +1,301 @@
+use std::ops::Range;
+use std::slice;
+
+// Set of values given as half-open intervals
+//
+// the intervals are kept sorted, non-empty and neither overlapping nor adjacent,
+// so two sets with the same values compare equal
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Range<T>>
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+
+    // normalizes the intervals: drops empty ones, merges overlapping and adjacent ones
+    pub fn from_intervals(intervals: impl IntoIterator<Item = Range<T>>) -> IntervalSet<T> {
+        let mut intervals: Vec<Range<T>> = intervals.into_iter().filter(|interval| interval.start < interval.end).collect();
+        intervals.sort_by_key(|interval| interval.start);
+
+        let mut normalized: Vec<Range<T>> = vec![];
+        for interval in intervals {
+            match normalized.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => normalized.push(interval)
+            }
+        }
+
+        IntervalSet { intervals: normalized }
+    }
+
+    pub fn insert(&mut self, interval: Range<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+
+        *self = IntervalSet::from_intervals(intervals);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        // index of the first interval ending after the value
+        let index = self.intervals.partition_point(|interval| interval.end <= value);
+
+        self.intervals.get(index).is_some_and(|interval| interval.start <= value)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max_end(&self) -> Option<T> {
+        self.intervals.last().map(|interval| interval.end)
+    }
+
+    pub fn intervals(&self) -> &[Range<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Range<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        IntervalSet::from_intervals(self.intervals.iter().chain(other.intervals.iter()).cloned())
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intersection = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (&self.intervals[i], &other.intervals[j]);
+
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                intersection.push(start..end);
+            }
+
+            // the interval ending first cannot intersect with any further interval
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        IntervalSet { intervals: intersection }
+    }
+
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = vec![];
+        let mut j = 0;
+
+        for interval in self.intervals.iter() {
+            let mut start = interval.start;
+
+            // skip the intervals to subtract ending before this one
+            while j < other.intervals.len() && other.intervals[j].end <= start {
+                j += 1;
+            }
+
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < interval.end {
+                if start < other.intervals[k].start {
+                    difference.push(start..other.intervals[k].start);
+                }
+                start = start.max(other.intervals[k].end);
+                k += 1;
+            }
+
+            if start < interval.end {
+                difference.push(start..interval.end);
+            }
+        }
+
+        IntervalSet { intervals: difference }
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(intervals: I) -> IntervalSet<T> {
+        IntervalSet::from_intervals(intervals)
+    }
+}
+
+impl<T: Copy + Ord> From<Range<T>> for IntervalSet<T> {
+    fn from(interval: Range<T>) -> IntervalSet<T> {
+        IntervalSet::from_intervals([interval])
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Range<T>;
+    type IntoIter = slice::Iter<'a, Range<T>>;
+
+    fn into_iter(self) -> slice::Iter<'a, Range<T>> {
+        self.intervals.iter()
+    }
+}
+
+impl<T> IntoIterator for IntervalSet<T> {
+    type Item = Range<T>;
+    type IntoIter = std::vec::IntoIter<Range<T>>;
+
+    fn into_iter(self) -> std::vec::IntoIter<Range<T>> {
+        self.intervals.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::interval::IntervalSet;
+
+    // all sets of values within 0..WIDTH, the bits of the index tell which values are included
+    const WIDTH: i32 = 7;
+
+    fn set_of_bits(bits: u32, offset: i32) -> IntervalSet<i32> {
+        (0..WIDTH)
+            .filter(|value| bits & (1 << value) != 0)
+            .map(|value| value + offset..value + offset + 1)
+            .collect()
+    }
+
+    fn bits_of_set(set: &IntervalSet<i32>, offset: i32) -> u32 {
+        (0..WIDTH)
+            .filter(|value| set.contains(value + offset))
+            .fold(0, |bits, value| bits | (1 << value))
+    }
+
+    mod from_intervals {
+        use std::ops::Range;
+
+        use crate::interval::IntervalSet;
+
+        #[test]
+        fn it_should_merge_overlapping_and_adjacent_intervals() {
+            assert_eq!(
+                vec![0u32..7, 9..12],
+
+                IntervalSet::from_intervals([5..7, 0..3, 2..4, 9..10, 4..5, 10..12]).intervals()
+            );
+        }
+
+        #[test]
+        fn it_should_drop_empty_intervals() {
+            assert_eq!(vec![-3i64..-1], IntervalSet::from_intervals([4..4, -3..-1, Range { start: 9, end: 2 }]).intervals());
+            assert!(IntervalSet::<u8>::from_intervals([]).is_empty());
+        }
+
+        #[test]
+        fn it_should_keep_intervals_nested_in_others() {
+            assert_eq!(vec![0u8..10], IntervalSet::from_intervals([0..10, 2..3, 5..10]).intervals());
+        }
+
+        #[test]
+        fn it_should_normalize_on_insert() {
+            let mut set = IntervalSet::from(10u64..20);
+            set.insert(0..5);
+            set.insert(5..10);
+            set.insert(30..40);
+
+            assert_eq!(vec![0..20, 30..40], set.intervals());
+        }
+    }
+
+    mod contains {
+        use crate::interval::IntervalSet;
+
+        #[test]
+        fn it_should_contain_values_within_the_intervals() {
+            let set = IntervalSet::from_intervals([-5i32..-2, 3..4, 10..20]);
+
+            let contained: Vec<i32> = (-10..25).filter(|value| set.contains(*value)).collect();
+
+            assert_eq!(vec![-5, -4, -3, 3, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19], contained);
+        }
+
+        #[test]
+        fn it_should_handle_the_bounds_of_the_type() {
+            let set = IntervalSet::from(u32::MAX - 1..u32::MAX);
+
+            assert!(set.contains(u32::MAX - 1));
+            assert!(!set.contains(u32::MAX));
+            assert!(!set.contains(0));
+            assert_eq!(Some(u32::MAX - 1), set.min());
+            assert_eq!(Some(u32::MAX), set.max_end());
+        }
+    }
+
+    mod operations {
+        use crate::interval::IntervalSet;
+        use crate::interval::test::{bits_of_set, set_of_bits, WIDTH};
+
+        // compares each operation on all pairs of sets within 0..WIDTH with the bitwise operation
+        fn check_all_pairs(offset: i32) {
+            for a in 0..1 << WIDTH {
+                for b in 0..1 << WIDTH {
+                    let (set_a, set_b) = (set_of_bits(a, offset), set_of_bits(b, offset));
+
+                    assert_eq!(a | b, bits_of_set(&set_a.union(&set_b), offset), "{:?} | {:?}", set_a, set_b);
+                    assert_eq!(a & b, bits_of_set(&set_a.intersection(&set_b), offset), "{:?} & {:?}", set_a, set_b);
+                    assert_eq!(a & !b, bits_of_set(&set_a.difference(&set_b), offset), "{:?} - {:?}", set_a, set_b);
+
+                    // results are normalized, so equal sets are built from equal intervals
+                    assert_eq!(set_of_bits(a | b, offset), set_a.union(&set_b));
+                    assert_eq!(set_of_bits(a & b, offset), set_a.intersection(&set_b));
+                    assert_eq!(set_of_bits(a & !b, offset), set_a.difference(&set_b));
+                }
+            }
+        }
+
+        #[test]
+        fn it_should_match_set_operations_on_all_small_sets() {
+            check_all_pairs(0);
+        }
+
+        #[test]
+        fn it_should_match_set_operations_on_negative_values() {
+            check_all_pairs(-4);
+        }
+
+        #[test]
+        fn it_should_work_on_unsigned_values() {
+            let a = IntervalSet::from_intervals([0u8..10, 20..30, 250..255]);
+            let b = IntervalSet::from_intervals([5u8..25, 254..255]);
+
+            assert_eq!(vec![0..30, 250..255], a.union(&b).intervals());
+            assert_eq!(vec![5..10, 20..25, 254..255], a.intersection(&b).intervals());
+            assert_eq!(vec![0..5, 25..30, 250..254], a.difference(&b).intervals());
+            assert_eq!(vec![10..20], b.difference(&a).intervals());
+        }
+    }
+
+    mod iter {
+        use crate::interval::IntervalSet;
+
+        #[test]
+        fn it_should_iterate_over_the_normalized_intervals() {
+            let set = IntervalSet::from_intervals([7i16..9, -2..1, 0..3]);
+
+            assert_eq!(vec![&(-2..3), &(7..9)], set.iter().collect::<Vec<_>>());
+            assert_eq!(vec![-2..3, 7..9], set.into_iter().collect::<Vec<_>>());
+        }
+    }
+
+}
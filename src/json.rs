@@ -0,0 +1,106 @@
+use std::fmt;
+
+// Minimal JSON document model, serialized compactly by Display
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    pub fn string(value: &str) -> Json {
+        Json::String(value.to_string())
+    }
+
+    // object with the keys in the given order
+    pub fn object(members: Vec<(&str, Json)>) -> Json {
+        Json::Object(
+            members.into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect())
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_str("\"")?;
+
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?
+        }
+    }
+
+    f.write_str("\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Number(value) => write!(f, "{}", value),
+            Json::String(value) => write_string(f, value),
+            Json::Array(values) => {
+                f.write_str("[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_str("]")
+            }
+            Json::Object(members) => {
+                f.write_str("{")?;
+                for (index, (key, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod display {
+        use crate::json::Json;
+
+        #[test]
+        fn it_should_serialize_values() {
+            assert_eq!("null", Json::Null.to_string());
+            assert_eq!("true", Json::Bool(true).to_string());
+            assert_eq!("-42", Json::Number(-42).to_string());
+            assert_eq!("[1,\"2\",[]]", Json::Array(vec![Json::Number(1), Json::string("2"), Json::Array(vec![])]).to_string());
+            assert_eq!(
+                "{\"day\":5,\"part1\":null}",
+                Json::object(vec![("day", Json::Number(5)), ("part1", Json::Null)]).to_string()
+            );
+        }
+
+        #[test]
+        fn it_should_escape_strings() {
+            assert_eq!(
+                r#""line 1, column 7: invalid number '4\"x'\n\\ \t\u0001""#,
+                Json::string("line 1, column 7: invalid number '4\"x'\n\\ \t\u{1}").to_string()
+            );
+            assert_eq!("\"µs\"", Json::string("µs").to_string());
+        }
+    }
+}
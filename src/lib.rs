@@ -1,12 +1,347 @@
+use std::io;
+
+use parse_error::ParseError;
+
 pub mod input_helper {
-    use std::fs::File;
-    use std::io::{Lines, Result, BufReader};
+    use std::fmt;
+    use std::fs::{self, File};
+    use std::io::{self, Lines, Result, BufReader};
     use std::io::prelude::*;
 
+    use crate::parse_error::{ParseError, ParseErrorKind};
+
     pub fn read_lines(file: &str) -> Result<Lines<impl BufRead>> {
         let input_file = File::open(file)?;
         let reader = BufReader::new(input_file);
-        
+
         Ok(reader.lines())
     }
+
+    // where the puzzle input is read from
+    #[derive(Clone)]
+    #[derive(Debug)]
+    #[derive(PartialEq)]
+    pub enum Input {
+        File(String),
+        Stdin,
+        Text(String)
+    }
+
+    impl Input {
+        // "-" selects stdin, anything else is a file path
+        pub fn from_arg(arg: &str) -> Input {
+            if arg == "-" {
+                Input::Stdin
+            } else {
+                Input::File(arg.to_string())
+            }
+        }
+
+        // inline input, taken as is
+        pub fn from_text(text: &str) -> Input {
+            Input::Text(text.to_string())
+        }
+
+        // inline input given on the command line, "\n" separates lines and "\\" stands for a backslash
+        pub fn from_escaped_text(text: &str) -> Input {
+            let mut unescaped = String::with_capacity(text.len());
+            let mut chars = text.chars();
+
+            while let Some(char) = chars.next() {
+                match (char, chars.clone().next()) {
+                    ('\\', Some('n')) => { unescaped.push('\n'); chars.next(); }
+                    ('\\', Some('\\')) => { unescaped.push('\\'); chars.next(); }
+                    _ => unescaped.push(char)
+                }
+            }
+
+            Input::Text(unescaped)
+        }
+
+        // normalized lines, see normalize()
+        pub fn read_lines(&self) -> Result<Vec<String>> {
+            Ok(self.read_normalized()?.lines)
+        }
+
+        pub fn read_normalized(&self) -> Result<Normalized> {
+            let content =
+                match self {
+                    Input::File(path) =>
+                        fs::read_to_string(path)
+                            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path, err)))?,
+                    Input::Stdin => {
+                        let mut content = String::new();
+                        io::stdin().lock().read_to_string(&mut content)?;
+                        content
+                    }
+                    Input::Text(text) =>
+                        text.clone()
+                };
+
+            Ok(normalize(&content))
+        }
+    }
+
+    // lines of an input together with what normalize() had to change to get them
+    #[derive(Debug)]
+    #[derive(PartialEq)]
+    pub struct Normalized {
+        pub lines: Vec<String>,
+        pub warnings: Vec<String>
+    }
+
+    // makes inputs saved by other editors and platforms look like the ones the solvers expect:
+    // strips a byte order mark, carriage returns, trailing whitespace and empty lines at the end
+    //
+    // lines that only consisted of whitespace become empty, so they still separate the sections of an input
+    pub fn normalize(content: &str) -> Normalized {
+        let mut warnings = vec![];
+
+        let content =
+            match content.strip_prefix('\u{feff}') {
+                Some(content) => {
+                    warnings.push("removed the byte order mark".to_string());
+                    content
+                }
+                None => content
+            };
+
+        let (mut carriage_returns, mut trailing_whitespace) = (0, 0);
+        let mut lines: Vec<String> =
+            content.split_terminator('\n')
+                .map(|line| {
+                    if line.contains('\r') {
+                        carriage_returns += 1;
+                    }
+
+                    let line = line.replace('\r', "");
+                    let trimmed = line.trim_end_matches([' ', '\t']);
+                    if trimmed.len() != line.len() {
+                        trailing_whitespace += 1;
+                    }
+
+                    trimmed.to_string()
+                })
+                .collect();
+
+        if carriage_returns > 0 {
+            warnings.push(format!("removed carriage returns from {} lines", carriage_returns));
+        }
+        if trailing_whitespace > 0 {
+            warnings.push(format!("trimmed trailing whitespace from {} lines", trailing_whitespace));
+        }
+
+        let length = lines.iter().rposition(|line| !line.is_empty()).map_or(0, |index| index + 1);
+        if length < lines.len() {
+            warnings.push(format!("removed {} empty lines at the end", lines.len() - length));
+            lines.truncate(length);
+        }
+
+        Normalized { lines, warnings }
+    }
+
+    // checks that all lines have the same number of characters, as the grid based days require
+    pub fn validate_rectangular(lines: &[String]) -> std::result::Result<(), ParseError> {
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        for (y, line) in lines.iter().enumerate() {
+            let length = line.chars().count();
+            if length != width {
+                return Err(ParseError::new(y+1, length.min(width) + 1, ParseErrorKind::InvalidLength { expected: width, found: length }));
+            }
+        }
+
+        Ok(())
+    }
+
+    // 64 bit FNV-1a hash, stable across platforms and releases
+    pub fn fnv1a(bytes: &[u8]) -> u64 {
+        bytes.iter()
+            .fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+    }
+
+    // identifies an input by its content, independent of line endings
+    pub fn hash_lines(lines: &[String]) -> u64 {
+        let mut content = Vec::new();
+        for line in lines {
+            content.extend_from_slice(line.as_bytes());
+            content.push(b'\n');
+        }
+
+        fnv1a(&content)
+    }
+
+    impl fmt::Display for Input {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Input::File(path) => f.write_str(path),
+                Input::Stdin => f.write_str("<stdin>"),
+                Input::Text(_) => f.write_str("<text>")
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        mod input {
+            use crate::input_helper::Input;
+
+            #[test]
+            fn it_should_select_stdin_for_a_dash() {
+                assert_eq!(Input::Stdin, Input::from_arg("-"));
+                assert_eq!(Input::File("input/day01".to_string()), Input::from_arg("input/day01"));
+            }
+
+            #[test]
+            fn it_should_split_inline_text_into_lines() {
+                assert_eq!(
+                    vec!["1abc2", "pqr3stu8vwx"],
+
+                    Input::from_escaped_text("1abc2\\npqr3stu8vwx").read_lines().unwrap(),
+                    "escaped line separator"
+                );
+
+                assert_eq!(
+                    vec!["1abc2", "pqr3stu8vwx"],
+
+                    Input::from_text("1abc2\npqr3stu8vwx\n").read_lines().unwrap(),
+                    "literal line separator"
+                );
+            }
+
+            #[test]
+            fn it_should_only_unescape_text_given_as_escaped() {
+                assert_eq!(Input::Text("a\\nb".to_string()), Input::from_text("a\\nb"));
+                assert_eq!(Input::Text("a\\nb".to_string()), Input::from_escaped_text("a\\\\nb"));
+                assert_eq!(Input::Text("a\\\nb\\".to_string()), Input::from_escaped_text("a\\\\\\nb\\"));
+                assert_eq!(Input::Text("C:\\dir".to_string()), Input::from_escaped_text("C:\\dir"), "other backslashes are kept");
+            }
+
+            #[test]
+            fn it_should_read_lines_from_a_file() {
+                assert_eq!(
+                    Some("Time:        49     97     94     94".to_string()),
+
+                    Input::from_arg("input/day06").read_lines().unwrap().into_iter().next()
+                );
+            }
+
+            #[test]
+            fn it_should_name_the_file_in_errors() {
+                let err = Input::from_arg("input/day99").read_lines().unwrap_err();
+
+                assert!(err.to_string().starts_with("input/day99: "));
+            }
+        }
+
+        mod normalize {
+            use crate::input_helper::{normalize, Normalized};
+
+            #[test]
+            fn it_should_keep_normalized_content() {
+                assert_eq!(
+                    Normalized { lines: vec!["seeds: 79 14".to_string(), "".to_string(), "soil map:".to_string()], warnings: vec![] },
+
+                    normalize("seeds: 79 14\n\nsoil map:\n")
+                );
+
+                assert_eq!(Normalized { lines: vec![], warnings: vec![] }, normalize(""));
+            }
+
+            #[test]
+            fn it_should_report_what_was_changed() {
+                assert_eq!(
+                    Normalized {
+                        lines: vec!["seeds: 79 14".to_string(), "".to_string(), "soil map:".to_string()],
+                        warnings: vec![
+                            "removed the byte order mark".to_string(),
+                            "removed carriage returns from 4 lines".to_string(),
+                            "trimmed trailing whitespace from 2 lines".to_string(),
+                            "removed 1 empty lines at the end".to_string()
+                        ]
+                    },
+
+                    normalize("\u{feff}seeds: 79 14 \r\n \t\r\nsoil map:\r\n\r\n")
+                );
+            }
+
+            #[test]
+            fn it_should_remove_lone_carriage_returns() {
+                assert_eq!(vec!["LR", "", "AAA = (BBB, CCC)"], normalize("LR\n\r\nAAA = (BBB, CCC)\r").lines);
+            }
+        }
+
+        mod validate_rectangular {
+            use crate::input_helper::validate_rectangular;
+            use crate::parse_error::{ParseError, ParseErrorKind};
+
+            #[test]
+            fn it_should_accept_lines_of_equal_length() {
+                assert_eq!(Ok(()), validate_rectangular(&["S-7".to_string(), "|é|".to_string()]));
+                assert_eq!(Ok(()), validate_rectangular(&[]));
+            }
+
+            #[test]
+            fn it_should_report_the_first_line_of_a_different_length() {
+                assert_eq!(
+                    Err(ParseError::new(3, 3, ParseErrorKind::InvalidLength { expected: 3, found: 2 })),
+
+                    validate_rectangular(&["S-7".to_string(), "|.|".to_string(), "L-".to_string(), "L".to_string()])
+                );
+            }
+        }
+
+        mod hash_lines {
+            use crate::input_helper::{fnv1a, hash_lines};
+
+            #[test]
+            fn it_should_hash_the_lines_with_fnv1a() {
+                assert_eq!(0xcbf29ce484222325, fnv1a(b""));
+                assert_eq!(0xaf63dc4c8601ec8c, fnv1a(b"a"));
+                assert_eq!(fnv1a(b"0 3 6\n1 3 6\n"), hash_lines(&["0 3 6".to_string(), "1 3 6".to_string()]));
+            }
+        }
+    }
+}
+
+pub mod aho_corasick;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod grid;
+pub mod http;
+pub mod interval;
+pub mod json;
+pub mod math;
+pub mod parse_error;
+pub mod pool;
+pub mod random;
+pub mod render;
+pub mod runner;
+pub mod shell;
+pub mod trace;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+
+// Common interface of all puzzle solutions
+//
+// parse() turns the puzzle input into the day's model,
+// part1() and part2() compute the answers from that model
+pub trait Solution {
+    fn parse(lines: &[String]) -> Result<Self, ParseError>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> io::Result<String>;
+    fn part2(&self) -> io::Result<String>;
 }
@@ -0,0 +1,301 @@
+use std::{error, fmt, io};
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum MathError {
+    Overflow,
+    NoSolution,
+    NotInvertible,
+    InvalidModulus
+}
+
+impl fmt::Display for MathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MathError::Overflow => write!(f, "arithmetic overflow"),
+            MathError::NoSolution => write!(f, "no solution"),
+            MathError::NotInvertible => write!(f, "value is not invertible"),
+            MathError::InvalidModulus => write!(f, "modulus must be positive")
+        }
+    }
+}
+
+impl error::Error for MathError {}
+
+impl From<MathError> for io::Error {
+    fn from(err: MathError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+// primitive integer types the functions of this module work with
+pub trait Integer: Copy + Ord + fmt::Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+
+    // remainder of a truncating division, which cannot overflow (MIN % -1 is 0)
+    fn remainder(self, other: Self) -> Self;
+}
+
+// integers with negative values, as needed for Bézout coefficients
+pub trait SignedInteger: Integer {}
+
+macro_rules! impl_integer {
+    ($($t:ty),*; $abs:expr) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> { <$t>::checked_add(self, other) }
+                fn checked_sub(self, other: Self) -> Option<Self> { <$t>::checked_sub(self, other) }
+                fn checked_mul(self, other: Self) -> Option<Self> { <$t>::checked_mul(self, other) }
+                fn checked_div(self, other: Self) -> Option<Self> { <$t>::checked_div(self, other) }
+                fn checked_abs(self) -> Option<Self> { $abs(self) }
+                fn remainder(self, other: Self) -> Self { <$t>::wrapping_rem(self, other) }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize; Some);
+impl_integer!(i8, i16, i32, i64, i128, isize; |value: Self| value.checked_abs());
+
+impl SignedInteger for i8 {}
+impl SignedInteger for i16 {}
+impl SignedInteger for i32 {}
+impl SignedInteger for i64 {}
+impl SignedInteger for i128 {}
+impl SignedInteger for isize {}
+
+// greatest common divisor, never negative - gcd(0, 0) is 0
+pub fn gcd<T: Integer>(a: T, b: T) -> Result<T, MathError> {
+    let (mut a, mut b) = (a, b);
+
+    while b != T::ZERO {
+        (a, b) = (b, a.remainder(b));
+    }
+
+    a.checked_abs().ok_or(MathError::Overflow)
+}
+
+// least common multiple, never negative - 0 if any of the values is 0
+pub fn lcm<T: Integer>(a: T, b: T) -> Result<T, MathError> {
+    if a == T::ZERO || b == T::ZERO {
+        return Ok(T::ZERO);
+    }
+
+    (a.checked_div(gcd(a, b)?).ok_or(MathError::Overflow)?)
+        .checked_mul(b)
+        .and_then(|value| value.checked_abs())
+        .ok_or(MathError::Overflow)
+}
+
+// extended Euclidean algorithm: returns (g, x, y) with a*x + b*y = g = gcd(a, b)
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> Result<(T, T, T), MathError> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    // next = previous - quotient * current
+    let step = |previous: T, current: T, quotient: T| {
+        quotient.checked_mul(current)
+            .and_then(|product| previous.checked_sub(product))
+            .ok_or(MathError::Overflow)
+    };
+
+    while r != T::ZERO {
+        let quotient = old_r.checked_div(r).ok_or(MathError::Overflow)?;
+
+        (old_r, r) = (r, step(old_r, r, quotient)?);
+        (old_x, x) = (x, step(old_x, x, quotient)?);
+        (old_y, y) = (y, step(old_y, y, quotient)?);
+    }
+
+    if old_r < T::ZERO {
+        let negate = |value: T| T::ZERO.checked_sub(value).ok_or(MathError::Overflow);
+
+        Ok((negate(old_r)?, negate(old_x)?, negate(old_y)?))
+    } else {
+        Ok((old_r, old_x, old_y))
+    }
+}
+
+// remainder in 0..modulus
+pub fn modulo<T: SignedInteger>(value: T, modulus: T) -> Result<T, MathError> {
+    if modulus <= T::ZERO {
+        return Err(MathError::InvalidModulus);
+    }
+
+    let remainder = value.remainder(modulus);
+
+    if remainder < T::ZERO {
+        remainder.checked_add(modulus).ok_or(MathError::Overflow)
+    } else {
+        Ok(remainder)
+    }
+}
+
+// x in 0..modulus with a*x = 1 (mod modulus)
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Result<T, MathError> {
+    let (g, x, _) = extended_gcd(modulo(a, modulus)?, modulus)?;
+
+    if g != T::ONE {
+        return Err(MathError::NotInvertible);
+    }
+
+    modulo(x, modulus)
+}
+
+// solves the system x = remainder (mod modulus) for all given (remainder, modulus)
+//
+// the moduli do not need to be coprime, returns (x, lcm of all moduli) with x in 0..lcm
+pub fn chinese_remainder<T: SignedInteger>(congruences: &[(T, T)]) -> Result<(T, T), MathError> {
+    let mut solution = (T::ZERO, T::ONE);
+
+    for &(remainder, modulus) in congruences {
+        let (x, combined_modulus) = solution;
+        let remainder = modulo(remainder, modulus)?;
+
+        // x + combined_modulus * k = remainder (mod modulus)
+        let (g, inverse, _) = extended_gcd(combined_modulus, modulus)?;
+        let difference = remainder.checked_sub(x).ok_or(MathError::Overflow)?;
+        if difference.remainder(g) != T::ZERO {
+            return Err(MathError::NoSolution);
+        }
+
+        let reduced_modulus = modulus.checked_div(g).ok_or(MathError::Overflow)?;
+        let k =
+            modulo(difference.checked_div(g).ok_or(MathError::Overflow)?, reduced_modulus)?
+                .checked_mul(modulo(inverse, reduced_modulus)?)
+                .ok_or(MathError::Overflow)?;
+        let k = modulo(k, reduced_modulus)?;
+
+        let next_modulus = combined_modulus.checked_mul(reduced_modulus).ok_or(MathError::Overflow)?;
+        let next_x =
+            combined_modulus.checked_mul(k)
+                .and_then(|offset| x.checked_add(offset))
+                .ok_or(MathError::Overflow)?;
+
+        solution = (modulo(next_x, next_modulus)?, next_modulus);
+    }
+
+    Ok(solution)
+}
+
+#[cfg(test)]
+mod test {
+    mod gcd {
+        use crate::math::{gcd, MathError};
+
+        #[test]
+        fn it_should_calculate_the_greatest_common_divisor_for_primes() {
+            assert_eq!(Ok(1), gcd(3, 5));
+            assert_eq!(Ok(1), gcd(5, 7));
+            assert_eq!(Ok(1), gcd(3, 11));
+        }
+
+        #[test]
+        fn it_should_calculate_the_greatest_common_divisor_for_numbers_with_common_prime_factors() {
+            assert_eq!(Ok(5), gcd(3*5, 5*7));
+            assert_eq!(Ok(3*3), gcd(2*3*3*5, 3*3*7));
+            assert_eq!(Ok(15u128), gcd(u128::MAX, 15));
+        }
+
+        #[test]
+        fn it_should_handle_zero_and_negative_values() {
+            assert_eq!(Ok(7), gcd(0, 7));
+            assert_eq!(Ok(0), gcd(0usize, 0));
+            assert_eq!(Ok(4), gcd(-12, 8));
+            assert_eq!(Ok(1), gcd(i64::MIN, -1));
+            assert_eq!(Err(MathError::Overflow), gcd(i64::MIN, 0), "|i64::MIN| is not an i64");
+        }
+    }
+
+    mod lcm {
+        use crate::math::{lcm, MathError};
+
+        #[test]
+        fn it_should_calculate_the_least_common_multiple() {
+            assert_eq!(Ok(12), lcm(4, 6));
+            assert_eq!(Ok(21), lcm(-3, 7));
+            assert_eq!(Ok(0), lcm(0u8, 5));
+        }
+
+        #[test]
+        fn it_should_report_overflows() {
+            assert_eq!(Ok(200u8), lcm(100, 8));
+            assert_eq!(Err(MathError::Overflow), lcm(100u8, 3));
+            assert_eq!(Err(MathError::Overflow), lcm(usize::MAX, usize::MAX - 1));
+        }
+    }
+
+    mod extended_gcd {
+        use crate::math::extended_gcd;
+
+        #[test]
+        fn it_should_return_bezout_coefficients() {
+            for (a, b) in [(240i64, 46), (46, 240), (-240, 46), (17, 0), (0, -5), (1_000_000_007, 998_244_353)] {
+                let (g, x, y) = extended_gcd(a, b).unwrap();
+
+                assert!(g >= 0, "gcd of {} and {} is not negative", a, b);
+                assert_eq!(g, a*x + b*y, "{} * {} + {} * {}", a, x, b, y);
+            }
+
+            assert_eq!(Ok((2, -9, 47)), extended_gcd(240, 46));
+        }
+    }
+
+    mod mod_inverse {
+        use crate::math::{mod_inverse, MathError};
+
+        #[test]
+        fn it_should_invert_values_coprime_to_the_modulus() {
+            assert_eq!(Ok(4), mod_inverse(3, 11));
+            assert_eq!(Ok(7), mod_inverse(-3, 11));
+            assert_eq!(Ok(0), mod_inverse(5, 1));
+        }
+
+        #[test]
+        fn it_should_reject_values_that_are_not_invertible() {
+            assert_eq!(Err(MathError::NotInvertible), mod_inverse(4, 12));
+            assert_eq!(Err(MathError::InvalidModulus), mod_inverse(3, 0));
+            assert_eq!(Err(MathError::InvalidModulus), mod_inverse(3, -7));
+        }
+    }
+
+    mod chinese_remainder {
+        use crate::math::{chinese_remainder, MathError};
+
+        #[test]
+        fn it_should_solve_congruences_with_coprime_moduli() {
+            assert_eq!(Ok((23, 105)), chinese_remainder(&[(2, 3), (3, 5), (2, 7)]));
+            assert_eq!(Ok((0, 1)), chinese_remainder::<i32>(&[]));
+        }
+
+        #[test]
+        fn it_should_solve_congruences_with_common_factors_in_the_moduli() {
+            assert_eq!(Ok((10, 12)), chinese_remainder(&[(4, 6), (2, 4)]));
+            assert_eq!(Ok((5, 6)), chinese_remainder(&[(2, 3), (-1, 2), (5, 6)]));
+        }
+
+        #[test]
+        fn it_should_report_congruences_without_solution() {
+            assert_eq!(Err(MathError::NoSolution), chinese_remainder(&[(1, 4), (2, 6)]));
+            assert_eq!(Err(MathError::InvalidModulus), chinese_remainder(&[(1, 4), (2, 0)]));
+        }
+
+        #[test]
+        fn it_should_report_overflows() {
+            assert_eq!(Err(MathError::Overflow), chinese_remainder(&[(1i8, 11), (2, 13)]));
+            assert_eq!(Ok((1, 143)), chinese_remainder(&[(1i16, 11), (1, 13)]));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::{error, fmt, io};
+
+// Error of a puzzle parser, pointing at the offending position in the input
+//
+// line and column are 1-based, the column counts characters
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum ParseErrorKind {
+    MissingDelimiter(&'static str),
+    Missing(&'static str),
+    InvalidNumber(String),
+    Unexpected { expected: &'static str, found: String },
+    InvalidLength { expected: usize, found: usize }
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError { line, column, kind }
+    }
+
+    // error within a single line whose line number is filled in by the caller (see on_line)
+    //
+    // part must be a slice of line (or an empty slice at its end), its position determines the column
+    pub fn at(line: &str, part: &str, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(1, column_of(line, part), kind)
+    }
+
+    // error at the end of the given line
+    pub fn at_end(line: &str, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(1, line.chars().count() + 1, kind)
+    }
+
+    pub fn on_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+}
+
+// 1-based character column of part within line
+pub fn column_of(line: &str, part: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+
+    if part_start < line_start || part_start > line_start + line.len() {
+        return 1;
+    }
+
+    line[..part_start - line_start].chars().count() + 1
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingDelimiter(delimiter) => write!(f, "missing delimiter '{}'", delimiter),
+            ParseErrorKind::Missing(what) => write!(f, "missing {}", what),
+            ParseErrorKind::InvalidNumber(token) => write!(f, "invalid number '{}'", token),
+            ParseErrorKind::Unexpected { expected, found } => write!(f, "expected {} but found '{}'", expected, found),
+            ParseErrorKind::InvalidLength { expected, found } => write!(f, "expected {} elements but found {}", expected, found)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(err: ParseError) -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod column_of {
+        use crate::parse_error::column_of;
+
+        #[test]
+        fn it_should_determine_the_column_of_a_slice_within_its_line() {
+            let line = "Game 1: 3 blue";
+
+            assert_eq!(1, column_of(line, &line[..4]));
+            assert_eq!(9, column_of(line, &line[8..]));
+            assert_eq!(15, column_of(line, &line[14..]), "empty slice at the end of the line");
+        }
+
+        #[test]
+        fn it_should_fall_back_to_the_first_column_for_unrelated_slices() {
+            assert_eq!(1, column_of("Game 1: 3 blue", "3 blue"));
+        }
+    }
+
+    mod display {
+        use crate::parse_error::{ParseError, ParseErrorKind};
+
+        #[test]
+        fn it_should_describe_position_and_kind() {
+            assert_eq!(
+                "line 3, column 12: expected blue, green or red but found 'purple'",
+
+                ParseError::new(3, 12, ParseErrorKind::Unexpected { expected: "blue, green or red", found: "purple".to_string() }).to_string()
+            );
+
+            assert_eq!(
+                "line 2, column 5: missing delimiter ': '",
+
+                ParseError::at_end("Game", ParseErrorKind::MissingDelimiter(": ")).on_line(2).to_string()
+            );
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::thread;
+
+// Runs tasks on a fixed number of std threads
+//
+// results come back in the order of the tasks, a panicking task yields Err(message)
+// instead of taking down the other tasks
+
+pub type Task<'a, T> = Box<dyn FnOnce() -> T + Send + 'a>;
+
+pub fn available_jobs() -> usize {
+    thread::available_parallelism().map_or(1, |jobs| jobs.get())
+}
+
+pub fn run<'a, T: Send>(jobs: usize, tasks: Vec<Task<'a, T>>) -> Vec<Result<T, String>> {
+    let count = tasks.len();
+    let queue = Mutex::new(tasks.into_iter().enumerate());
+    let results: Mutex<Vec<Option<Result<T, String>>>> = Mutex::new((0..count).map(|_| None).collect());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, count.max(1)) {
+            scope.spawn(|| {
+                loop {
+                    // the lock is released before the task runs
+                    let next = queue.lock().unwrap().next();
+                    let Some((index, task)) = next else { break };
+
+                    let result = panic::catch_unwind(AssertUnwindSafe(task)).map_err(panic_message);
+                    results.lock().unwrap()[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results.into_inner().unwrap()
+        .into_iter()
+        .map(|result| result.expect("every task is run"))
+        .collect()
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod run {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::thread;
+        use std::time::Duration;
+
+        use crate::pool::{run, Task};
+
+        #[test]
+        fn it_should_return_the_results_in_the_order_of_the_tasks() {
+            let tasks: Vec<Task<usize>> =
+                (0..20usize)
+                    .map(|index| Box::new(move || {
+                        // later tasks finish first
+                        thread::sleep(Duration::from_millis((20 - index) as u64));
+                        index * index
+                    }) as Task<usize>)
+                    .collect();
+
+            let expected: Vec<Result<usize, String>> = (0..20).map(|index| Ok(index * index)).collect();
+
+            assert_eq!(expected, run(4, tasks));
+        }
+
+        #[test]
+        fn it_should_isolate_panicking_tasks() {
+            let tasks: Vec<Task<u32>> = vec![
+                Box::new(|| 1),
+                Box::new(|| panic!("day {} failed", 2)),
+                Box::new(|| panic!("static message")),
+                Box::new(|| 4)
+            ];
+
+            assert_eq!(
+                vec![Ok(1), Err("day 2 failed".to_string()), Err("static message".to_string()), Ok(4)],
+
+                run(2, tasks)
+            );
+        }
+
+        #[test]
+        fn it_should_borrow_from_the_caller_and_use_at_most_the_given_number_of_threads() {
+            let running = AtomicUsize::new(0);
+            let most_running = AtomicUsize::new(0);
+
+            let tasks: Vec<Task<()>> =
+                (0..12)
+                    .map(|_| Box::new(|| {
+                        let now_running = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        most_running.fetch_max(now_running, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(5));
+                        running.fetch_sub(1, Ordering::SeqCst);
+                    }) as Task<()>)
+                    .collect();
+
+            assert_eq!(12, run(3, tasks).len());
+            assert!(most_running.load(Ordering::SeqCst) <= 3);
+            assert!(run(0, Vec::<Task<()>>::new()).is_empty());
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use std::ops::Range;
+
+// Deterministic pseudo random numbers (SplitMix64) for the input generators
+//
+// the same seed yields the same sequence on every platform and release
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // uniformly distributed value in the range, which must not be empty
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(range.start < range.end, "empty range {:?}", range);
+
+        let span = range.end - range.start;
+        range.start + ((self.next_u64() as u128 * span as u128) >> 64) as u64
+    }
+
+    pub fn index(&mut self, length: usize) -> usize {
+        self.range(0..length as u64) as usize
+    }
+
+    // true with a probability of percent / 100
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(0..100) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.index(index + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod rng {
+        use crate::random::Rng;
+
+        #[test]
+        fn it_should_produce_the_splitmix64_sequence() {
+            let mut rng = Rng::new(1234567);
+
+            assert_eq!(6457827717110365317, rng.next_u64());
+            assert_eq!(3203168211198807973, rng.next_u64());
+        }
+
+        #[test]
+        fn it_should_stay_within_the_given_range() {
+            let mut rng = Rng::new(42);
+
+            for _ in 0..1000 {
+                let value = rng.range(10..13);
+                assert!((10..13).contains(&value));
+            }
+
+            assert_eq!(7, rng.range(7..8));
+        }
+
+        #[test]
+        fn it_should_shuffle_deterministically() {
+            let mut items: Vec<u32> = (0..10).collect();
+            let mut other_items = items.clone();
+
+            Rng::new(7).shuffle(&mut items);
+            Rng::new(7).shuffle(&mut other_items);
+
+            assert_eq!(items, other_items);
+
+            items.sort();
+            assert_eq!((0..10).collect::<Vec<u32>>(), items);
+        }
+    }
+}
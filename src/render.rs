@@ -0,0 +1,144 @@
+use std::fmt::Write;
+
+use crate::grid::Grid;
+
+// Images of the grid based days, one colored square per tile
+
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct Color(pub u8, pub u8, pub u8);
+
+pub const BACKGROUND: Color = Color(24, 24, 32);
+pub const HIGHLIGHT: Color = Color(255, 200, 40);
+pub const POSITIVE: Color = Color(60, 200, 90);
+pub const NEGATIVE: Color = Color(220, 60, 60);
+pub const INSIDE: Color = Color(70, 130, 230);
+pub const SHADE: Color = Color(60, 60, 80);
+pub const FOREGROUND: Color = Color(240, 240, 240);
+
+pub const DEFAULT_SCALE: usize = 4;
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum ImageFormat {
+    Ppm,
+    Svg
+}
+
+impl ImageFormat {
+    // format by the extension of the path: .ppm or .svg
+    pub fn from_path(path: &str) -> Option<ImageFormat> {
+        let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+
+        match extension.as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None
+        }
+    }
+}
+
+pub fn render(tiles: &Grid<Color>, scale: usize, format: &ImageFormat) -> Vec<u8> {
+    match format {
+        ImageFormat::Ppm => to_ppm(tiles, scale),
+        ImageFormat::Svg => to_svg(tiles, scale).into_bytes()
+    }
+}
+
+// binary PPM (P6), each tile is a square of scale x scale pixels
+pub fn to_ppm(tiles: &Grid<Color>, scale: usize) -> Vec<u8> {
+    let mut image = format!("P6\n{} {}\n255\n", tiles.width() * scale, tiles.height() * scale).into_bytes();
+
+    for row in tiles.rows() {
+        let pixel_row: Vec<u8> =
+            row.iter()
+                .flat_map(|Color(r, g, b)| [*r, *g, *b].repeat(scale))
+                .collect();
+
+        for _ in 0..scale {
+            image.extend_from_slice(&pixel_row);
+        }
+    }
+
+    image
+}
+
+// SVG with the background as one rectangle and one rectangle per run of equally colored tiles
+pub fn to_svg(tiles: &Grid<Color>, scale: usize) -> String {
+    let (width, height) = (tiles.width() * scale, tiles.height() * scale);
+    let fill = |Color(r, g, b): Color| format!("#{:02x}{:02x}{:02x}", r, g, b);
+
+    let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n", width, height, width, height);
+    let _ = writeln!(svg, "<rect width=\"{}\" height=\"{}\" fill=\"{}\"/>", width, height, fill(BACKGROUND));
+
+    for (y, row) in tiles.rows().enumerate() {
+        let mut x = 0;
+
+        for run in row.chunk_by(|a, b| a == b) {
+            if run[0] != BACKGROUND {
+                let _ = writeln!(svg, "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>", x * scale, y * scale, run.len() * scale, scale, fill(run[0]));
+            }
+
+            x += run.len();
+        }
+    }
+
+    svg + "</svg>\n"
+}
+
+#[cfg(test)]
+mod test {
+    mod image_format {
+        use crate::render::ImageFormat;
+
+        #[test]
+        fn it_should_choose_the_format_by_extension() {
+            assert_eq!(Some(ImageFormat::Ppm), ImageFormat::from_path("day10.ppm"));
+            assert_eq!(Some(ImageFormat::Svg), ImageFormat::from_path("out/day11.SVG"));
+            assert_eq!(None, ImageFormat::from_path("day03.png"));
+            assert_eq!(None, ImageFormat::from_path("day03"));
+        }
+    }
+
+    mod to_ppm {
+        use crate::grid::Grid;
+        use crate::render::{to_ppm, Color};
+
+        #[test]
+        fn it_should_scale_each_tile_to_a_square() {
+            let tiles = Grid::from_rows(vec![vec![Color(1, 2, 3), Color(4, 5, 6)]]).unwrap();
+
+            let mut expected = b"P6\n4 2\n255\n".to_vec();
+            for _ in 0..2 {
+                expected.extend_from_slice(&[1, 2, 3, 1, 2, 3, 4, 5, 6, 4, 5, 6]);
+            }
+
+            assert_eq!(expected, to_ppm(&tiles, 2));
+        }
+    }
+
+    mod to_svg {
+        use crate::grid::Grid;
+        use crate::render::{to_svg, BACKGROUND, HIGHLIGHT, POSITIVE};
+
+        #[test]
+        fn it_should_draw_runs_of_equal_tiles_as_one_rectangle() {
+            let tiles = Grid::from_rows(vec![
+                vec![HIGHLIGHT, HIGHLIGHT, BACKGROUND],
+                vec![BACKGROUND, POSITIVE, POSITIVE]
+            ]).unwrap();
+
+            assert_eq!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"30\" height=\"20\" viewBox=\"0 0 30 20\" shape-rendering=\"crispEdges\">\n\
+                 <rect width=\"30\" height=\"20\" fill=\"#181820\"/>\n\
+                 <rect x=\"0\" y=\"0\" width=\"20\" height=\"10\" fill=\"#ffc828\"/>\n\
+                 <rect x=\"10\" y=\"10\" width=\"20\" height=\"10\" fill=\"#3cc85a\"/>\n\
+                 </svg>\n",
+
+                to_svg(&tiles, 10)
+            );
+        }
+    }
+}
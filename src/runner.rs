@@ -0,0 +1,1301 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use crate::{bench, trace};
+use crate::cache::{Cache, Key};
+use crate::grid::Grid;
+use crate::input_helper::{self, Input, Normalized};
+use crate::json::Json;
+use crate::parse_error::ParseError;
+use crate::pool::{self, Task};
+use crate::random::Rng;
+use crate::render::{self, Color, ImageFormat};
+use crate::shell::{Commands, Shell};
+use crate::{Solution, day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11};
+
+pub struct Day {
+    pub number: u8,
+    parse: fn(&[String]) -> io::Result<BoxedSolution>,
+    generate: fn(&mut Rng, usize) -> Vec<String>,
+    anonymize: AnonymizeFn,
+    render: Option<RenderFn>,
+    shell: Option<ShellFn>,
+    // bumped when a change of the solver can change its answers, it invalidates the cached ones
+    version: u32
+}
+
+// solutions can be shared with the threads solving their parts
+pub type BoxedSolution = Box<dyn Solution + Send + Sync>;
+
+type ShellFn = fn(&[String]) -> io::Result<Box<dyn Shell>>;
+
+type RenderFn = fn(&[String]) -> Result<Grid<Color>, ParseError>;
+
+type AnonymizeFn = fn(&mut Rng, &[String]) -> Result<Vec<String>, ParseError>;
+
+pub struct Answers {
+    pub day: u8,
+    pub part1: io::Result<String>,
+    pub part2: io::Result<String>
+}
+
+// answers together with the input they were computed from and the time each step took
+//
+// the warnings tell what normalizing the input changed, cached answers have no durations
+pub struct Run {
+    pub answers: Answers,
+    pub input: Input,
+    pub input_hash: Option<u64>,
+    pub durations: [Option<Duration>; 3],
+    pub warnings: Vec<String>,
+    pub cached: bool
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum Format {
+    Table,
+    Json
+}
+
+pub struct Options {
+    pub days: Vec<u8>,
+    pub input: Option<Input>,
+    pub bench: Option<BenchOptions>,
+    pub check: bool,
+    pub format: Format,
+    pub verbosity: u8,
+    pub jobs: usize,
+    // answers are read from and stored in the cache, never when checking, benchmarking or tracing
+    pub cache: bool,
+    pub clear_cache: bool
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct BenchOptions {
+    pub runs: usize,
+    pub csv: Option<String>
+}
+
+pub const DEFAULT_SEED: u64 = 1;
+pub const DEFAULT_SIZE: usize = 100;
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct GenerateOptions {
+    pub day: u8,
+    pub seed: u64,
+    pub size: usize
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct AnonymizeOptions {
+    pub day: u8,
+    pub input: Option<Input>,
+    pub seed: u64,
+    pub output: Option<String>
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct RenderOptions {
+    pub day: u8,
+    pub input: Option<Input>,
+    pub scale: usize,
+    pub output: String,
+    pub format: ImageFormat
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub struct ShellOptions {
+    pub day: u8,
+    pub input: Option<Input>
+}
+
+pub const DAYS: [Day; 11] = [
+    Day { number: 1, parse: parse_boxed::<day01::Day01>, generate: day01::generate, anonymize: day01::anonymize, render: None, shell: Some(shell_boxed::<day01::Day01>), version: 1 },
+    Day { number: 2, parse: parse_boxed::<day02::Day02>, generate: day02::generate, anonymize: day02::anonymize, render: None, shell: None, version: 1 },
+    Day { number: 3, parse: parse_boxed::<day03::Day03>, generate: day03::generate, anonymize: day03::anonymize, render: Some(render_day03), shell: None, version: 1 },
+    Day { number: 4, parse: parse_boxed::<day04::Day04>, generate: day04::generate, anonymize: day04::anonymize, render: None, shell: None, version: 1 },
+    Day { number: 5, parse: parse_boxed::<day05::Day05>, generate: day05::generate, anonymize: day05::anonymize, render: None, shell: Some(shell_boxed::<day05::Day05>), version: 2 },
+    Day { number: 6, parse: parse_boxed::<day06::Day06>, generate: day06::generate, anonymize: day06::anonymize, render: None, shell: None, version: 1 },
+    Day { number: 7, parse: parse_boxed::<day07::Day07>, generate: day07::generate, anonymize: day07::anonymize, render: None, shell: Some(shell_boxed::<day07::Day07>), version: 1 },
+    Day { number: 8, parse: parse_boxed::<day08::Day08>, generate: day08::generate, anonymize: day08::anonymize, render: None, shell: Some(shell_boxed::<day08::Day08>), version: 2 },
+    Day { number: 9, parse: parse_boxed::<day09::Day09>, generate: day09::generate, anonymize: day09::anonymize, render: None, shell: None, version: 1 },
+    Day { number: 10, parse: parse_boxed::<day10::Day10>, generate: day10::generate, anonymize: day10::anonymize, render: Some(render_day10), shell: None, version: 1 },
+    Day { number: 11, parse: parse_boxed::<day11::Day11>, generate: day11::generate, anonymize: day11::anonymize, render: Some(render_day11), shell: Some(shell_boxed::<day11::Day11>), version: 1 }
+];
+
+fn parse_boxed<S: Solution + Send + Sync + 'static>(lines: &[String]) -> io::Result<BoxedSolution> {
+    Ok(Box::new(S::parse(lines)?))
+}
+
+fn shell_boxed<S: Commands>(lines: &[String]) -> io::Result<Box<dyn Shell>> {
+    Ok(Box::new(S::parse(lines)?))
+}
+
+fn render_day03(lines: &[String]) -> Result<Grid<Color>, ParseError> {
+    let (schematic, numbers) = day03::parse_schematic(lines)?;
+
+    Ok(day03::render(&schematic, &numbers))
+}
+
+fn render_day10(lines: &[String]) -> Result<Grid<Color>, ParseError> {
+    Ok(day10::render(&day10::parse_map(lines)?))
+}
+
+fn render_day11(lines: &[String]) -> Result<Grid<Color>, ParseError> {
+    Ok(day11::render(&day11::parse_universe(lines)?))
+}
+
+impl Day {
+    pub fn input_path(&self) -> String {
+        format!("input/day{:02}", self.number)
+    }
+
+    pub fn answers_path(&self) -> String {
+        format!("answers/day{:02}.txt", self.number)
+    }
+
+    pub fn parse(&self, lines: &[String]) -> io::Result<BoxedSolution> {
+        (self.parse)(lines)
+    }
+
+    pub fn solve(&self, lines: &[String]) -> Answers {
+        match self.parse(lines) {
+            Ok(solution) =>
+                Answers {
+                    day: self.number,
+                    part1: self.solve_part(1, || solution.part1()),
+                    part2: self.solve_part(2, || solution.part2())
+                },
+            Err(err) => self.failed(err)
+        }
+    }
+
+    // key of the answers in the cache, together with the day, the part and the input hash
+    pub fn solver_version(&self) -> String {
+        format!("{}+{}", env!("CARGO_PKG_VERSION"), self.version)
+    }
+
+    pub fn default_input(&self) -> Input {
+        Input::File(self.input_path())
+    }
+
+    pub fn run(&self, input: &Input) -> Answers {
+        match input.read_lines() {
+            Ok(lines) => self.solve(&lines),
+            Err(err) => self.failed(err)
+        }
+    }
+
+    // like run(), but also records the input hash and the duration of each step
+    pub fn run_timed(&self, input: &Input) -> Run {
+        let mut durations = [None; 3];
+
+        let Normalized { lines, warnings } = match input.read_normalized() {
+            Ok(normalized) => normalized,
+            Err(err) => return Run { answers: self.failed(err), input: input.clone(), input_hash: None, durations, warnings: vec![], cached: false }
+        };
+
+        let start = Instant::now();
+        let answers =
+            match self.parse(&lines) {
+                Ok(solution) => {
+                    durations[0] = Some(start.elapsed());
+
+                    let start = Instant::now();
+                    let part1 = self.solve_part(1, || solution.part1());
+                    durations[1] = Some(start.elapsed());
+
+                    let start = Instant::now();
+                    let part2 = self.solve_part(2, || solution.part2());
+                    durations[2] = Some(start.elapsed());
+
+                    Answers { day: self.number, part1, part2 }
+                }
+                Err(err) => self.failed(err)
+            };
+
+        Run { answers, input: input.clone(), input_hash: Some(input_helper::hash_lines(&lines)), durations, warnings, cached: false }
+    }
+
+    // synthetic puzzle input, the same seed and size always give the same input
+    pub fn generate(&self, seed: u64, size: usize) -> Vec<String> {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+
+    // a structurally equivalent input that can be published instead of the given one,
+    // the same seed always gives the same input
+    pub fn anonymize(&self, seed: u64, lines: &[String]) -> io::Result<Vec<String>> {
+        Ok((self.anonymize)(&mut Rng::new(seed), lines)?)
+    }
+
+    pub fn can_render(&self) -> bool {
+        self.render.is_some()
+    }
+
+    // one colored tile per cell of the puzzle grid, for the days that have one
+    pub fn render(&self, lines: &[String]) -> io::Result<Grid<Color>> {
+        let render = self.render.ok_or(io::Error::new(io::ErrorKind::Unsupported, format!("day {} has no image", self.number)))?;
+
+        Ok(render(lines)?)
+    }
+
+    pub fn has_shell(&self) -> bool {
+        self.shell.is_some()
+    }
+
+    // the parsed input, ready to answer the commands of the day
+    pub fn shell(&self, lines: &[String]) -> io::Result<Box<dyn Shell>> {
+        let shell = self.shell.ok_or(io::Error::new(io::ErrorKind::Unsupported, format!("day {} has no shell commands", self.number)))?;
+
+        shell(lines)
+    }
+
+    // announces the part, so the trace of each part can be told apart
+    fn solve_part(&self, part: u8, solve: impl FnOnce() -> io::Result<String>) -> io::Result<String> {
+        crate::trace!(1, "day {} part {}:", self.number, part);
+
+        solve()
+    }
+
+    // the error is reported for both parts
+    fn failed(&self, err: io::Error) -> Answers {
+        Answers {
+            day: self.number,
+            part1: Err(io::Error::new(err.kind(), err.to_string())),
+            part2: Err(err)
+        }
+    }
+}
+
+// like run_timed() for each (day, input), with the days and their parts spread over the given number of threads
+//
+// the runs are returned in the given order, traces are printed afterwards in the same order
+// and a panicking day or part only fails that day or part, a single job included
+pub fn run_parallel(days: &[(&Day, Input)], jobs: usize) -> Vec<Run> {
+    let verbosity = trace::verbosity();
+
+    let parse_tasks: Vec<Task<_>> =
+        days.iter()
+            .map(|(day, input)| Box::new(move || trace::capture(verbosity, || {
+                let Normalized { lines, warnings } = input.read_normalized()?;
+
+                let start = Instant::now();
+                let solution = day.parse(&lines);
+
+                Ok(Parsed { input_hash: input_helper::hash_lines(&lines), solution, duration: start.elapsed(), warnings })
+            })) as Task<_>)
+            .collect();
+
+    let parsed: Vec<(io::Result<Parsed>, Vec<String>)> =
+        pool::run(jobs, parse_tasks).into_iter()
+            .map(|result| result.unwrap_or_else(|message| (Err(panicked(&message)), vec![])))
+            .collect();
+
+    // both parts of every parsed day
+    let part_tasks: Vec<Task<_>> =
+        days.iter().zip(parsed.iter())
+            .filter_map(|((day, _), (result, _))| match result {
+                Ok(Parsed { solution: Ok(solution), .. }) => Some((day, solution)),
+                _ => None
+            })
+            .flat_map(|(day, solution)|
+                [1, 2].map(|part| Box::new(move || trace::capture(verbosity, || {
+                    let start = Instant::now();
+                    let answer =
+                        if part == 1 {
+                            day.solve_part(1, || solution.part1())
+                        } else {
+                            day.solve_part(2, || solution.part2())
+                        };
+
+                    (answer, start.elapsed())
+                })) as Task<_>))
+            .collect();
+
+    let mut parts =
+        pool::run(jobs, part_tasks).into_iter()
+            .map(|result| result.unwrap_or_else(|message| ((Err(panicked(&message)), Duration::ZERO), vec![])));
+
+    days.iter().zip(parsed)
+        .map(|((day, input), (result, traces))| {
+            let mut traces = vec![traces];
+            let mut durations = [None; 3];
+
+            let (answers, input_hash, warnings) =
+                match result {
+                    Ok(Parsed { input_hash, solution: Ok(_), duration: parse_duration, warnings }) => {
+                        let ((part1, part1_duration), part1_traces) = parts.next().expect("part 1 was run");
+                        let ((part2, part2_duration), part2_traces) = parts.next().expect("part 2 was run");
+
+                        durations = [Some(parse_duration), Some(part1_duration), Some(part2_duration)];
+                        traces.extend([part1_traces, part2_traces]);
+
+                        (Answers { day: day.number, part1, part2 }, Some(input_hash), warnings)
+                    }
+                    Ok(Parsed { input_hash, solution: Err(err), warnings, .. }) => (day.failed(err), Some(input_hash), warnings),
+                    Err(err) => (day.failed(err), None, vec![])
+                };
+
+            traces.into_iter().flatten().for_each(trace::emit);
+
+            Run { answers, input: input.clone(), input_hash, durations, warnings, cached: false }
+        })
+        .collect()
+}
+
+// like run_parallel(), but the days with both answers in the cache are not solved
+//
+// the answers of the solved days are stored, a failing store only adds a warning to the run
+pub fn run_cached(days: &[(&Day, Input)], jobs: usize, cache: &Cache) -> Vec<Run> {
+    let mut runs: Vec<Option<Run>> = vec![];
+    let mut misses: Vec<(usize, Vec<String>)> = vec![];
+    let mut texts: Vec<(&Day, Input)> = vec![];
+
+    for (index, (day, input)) in days.iter().enumerate() {
+        let Normalized { lines, warnings } = match input.read_normalized() {
+            Ok(normalized) => normalized,
+            Err(err) => {
+                runs.push(Some(Run { answers: day.failed(err), input: input.clone(), input_hash: None, durations: [None; 3], warnings: vec![], cached: false }));
+                continue;
+            }
+        };
+
+        let input_hash = input_helper::hash_lines(&lines);
+        let version = day.solver_version();
+
+        match [1, 2].map(|part| cache.load(&Key { day: day.number, part, input_hash, version: &version })) {
+            [Some(part1), Some(part2)] => {
+                let answers = Answers { day: day.number, part1: Ok(part1), part2: Ok(part2) };
+                runs.push(Some(Run { answers, input: input.clone(), input_hash: Some(input_hash), durations: [None; 3], warnings, cached: true }));
+            }
+            _ => {
+                // stdin can't be read a second time, the day is solved from the lines already read
+                runs.push(None);
+                misses.push((index, warnings));
+                texts.push((*day, Input::Text(lines.join("\n"))));
+            }
+        }
+    }
+
+    let solved = run_parallel(&texts, jobs);
+
+    for ((index, warnings), (mut run, (day, _))) in misses.into_iter().zip(solved.into_iter().zip(texts.iter())) {
+        run.input = days[index].1.clone();
+        run.warnings = warnings;
+
+        let version = day.solver_version();
+        for (part, answer) in [(1, &run.answers.part1), (2, &run.answers.part2)] {
+            if let (Ok(answer), Some(input_hash)) = (answer, run.input_hash) {
+                if let Err(err) = cache.store(&Key { day: day.number, part, input_hash, version: &version }, answer) {
+                    run.warnings.push(format!("cannot cache the answer of part {} in {}: {}", part, cache.dir().display(), err));
+                }
+            }
+        }
+
+        runs[index] = Some(run);
+    }
+
+    runs.into_iter().map(|run| run.expect("every day is loaded or solved")).collect()
+}
+
+// parsed input of a day in run_parallel()
+struct Parsed {
+    input_hash: u64,
+    solution: io::Result<BoxedSolution>,
+    duration: Duration,
+    warnings: Vec<String>
+}
+
+fn panicked(message: &str) -> io::Error {
+    io::Error::other(format!("panicked: {}", message))
+}
+
+pub fn find_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+// parses a day selection like "7", "1..11", "3..=5" or "all"
+pub fn parse_day_selection(selection: &str) -> Result<Vec<u8>, String> {
+    if selection == "all" {
+        return Ok(DAYS.iter().map(|day| day.number).collect());
+    }
+
+    let parse_number = |number: &str| {
+        number.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("invalid day: '{}'", number))
+    };
+
+    let (first, last) =
+        if let Some((first, last)) = selection.split_once("..") {
+            let last = last.strip_prefix('=').unwrap_or(last);
+            (parse_number(first)?, parse_number(last)?)
+        } else {
+            let day = parse_number(selection)?;
+            (day, day)
+        };
+
+    if first > last {
+        return Err(format!("empty day range: '{}'", selection));
+    }
+
+    (first..=last)
+        .map(|number|
+            find_day(number)
+                .map(|day| day.number)
+                .ok_or(format!("no solution for day {}", number)))
+        .collect()
+}
+
+pub fn format_table(answers: &[Answers]) -> String {
+    let format_answer = |answer: &io::Result<String>| {
+        match answer {
+            Ok(value) => value.clone(),
+            Err(err) => format!("error: {}", err)
+        }
+    };
+
+    let rows: Vec<[String; 3]> =
+        answers.iter()
+            .map(|answers| [
+                answers.day.to_string(),
+                format_answer(&answers.part1),
+                format_answer(&answers.part2)
+            ])
+            .collect();
+
+    format_columns(["Day", "Part 1", "Part 2"], &rows)
+}
+
+// formats rows as a table below the given header, the first column is right-aligned
+pub fn format_columns<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let mut widths = header.map(|column| column.chars().count());
+    for row in rows.iter() {
+        for (width, column) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(column.chars().count());
+        }
+    }
+
+    let format_row = |row: &[&str]| {
+        row.iter().zip(widths.iter()).enumerate()
+            .map(|(index, (column, width))|
+                if index == 0 {
+                    format!("{:>w$}", column, w = width)
+                } else {
+                    format!("{:<w$}", column, w = width)
+                })
+            .collect::<Vec<String>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut table = format_row(&header) + "\n";
+    table += &widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<String>>().join("-+-");
+    table += "\n";
+    for row in rows.iter() {
+        table += &format_row(&row.each_ref().map(|column| column.as_str()));
+        table += "\n";
+    }
+
+    table
+}
+
+fn answer_to_json(answer: &io::Result<String>) -> Json {
+    match answer {
+        Ok(value) => Json::object(vec![("answer", Json::string(value))]),
+        Err(err) => Json::object(vec![("error", Json::String(err.to_string()))])
+    }
+}
+
+pub fn run_to_json(run: &Run) -> Json {
+    let duration_to_json = |duration: Option<Duration>| {
+        duration
+            .map(|duration| Json::Number(duration.as_nanos() as i64))
+            .unwrap_or(Json::Null)
+    };
+
+    Json::object(vec![
+        ("day", Json::Number(run.answers.day as i64)),
+        ("input", Json::String(run.input.to_string())),
+        ("input_hash", run.input_hash.map(|hash| Json::String(format!("{:016x}", hash))).unwrap_or(Json::Null)),
+        ("part1", answer_to_json(&run.answers.part1)),
+        ("part2", answer_to_json(&run.answers.part2)),
+        ("timings_ns", Json::object(vec![
+            ("parse", duration_to_json(run.durations[0])),
+            ("part1", duration_to_json(run.durations[1])),
+            ("part2", duration_to_json(run.durations[2]))
+        ])),
+        ("warnings", Json::Array(run.warnings.iter().map(|warning| Json::string(warning)).collect())),
+        ("cached", Json::Bool(run.cached))
+    ])
+}
+
+// JSON array with one object per day, each on its own line
+pub fn format_json(runs: &[Run]) -> String {
+    let days: Vec<String> = runs.iter().map(|run| run_to_json(run).to_string()).collect();
+
+    if days.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", days.join(",\n"))
+    }
+}
+
+// parses "--input <path>|-" or "--text <text>"
+// returns Ok(None) if the argument is not an input option
+fn parse_input_option(arg: &str, args: &mut impl Iterator<Item = String>) -> Result<Option<Input>, String> {
+    let input: fn(&str) -> Input =
+        match arg {
+            "--input" => Input::from_arg,
+            "--text" => Input::from_escaped_text,
+            _ => return Ok(None)
+        };
+
+    args.next()
+        .map(|value| Some(input(&value)))
+        .ok_or(format!("missing value for {}", arg))
+}
+
+// parses the arguments of the aoc binary:
+// [--input <path>|- | --text <text>] [-v|-vv] [--jobs <n> | --parallel] [--no-cache] [--clear-cache] [--format table|json | --check | --bench [--runs <n>] [--csv <path>]] <day>|<first>..<last>|all ...
+//
+// --clear-cache alone selects no day
+pub fn parse_options(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options {
+        days: vec![], input: None, bench: None, check: false, format: Format::Table, verbosity: 0, jobs: 1, cache: true, clear_cache: false
+    };
+    let mut args = args.into_iter();
+
+    let mut bench = false;
+    let mut runs = None;
+    let mut csv = None;
+
+    while let Some(arg) = args.next() {
+        if let Some(input) = parse_input_option(&arg, &mut args)? {
+            options.input = Some(input);
+        } else if arg == "--bench" {
+            bench = true;
+        } else if arg == "--check" {
+            options.check = true;
+        } else if arg == "--verbose" {
+            options.verbosity += 1;
+        } else if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v') {
+            // -v, -vv, ...
+            options.verbosity += (arg.len() - 1) as u8;
+        } else if arg == "--jobs" || arg == "-j" {
+            let value = args.next().ok_or(format!("missing value for {}", arg))?;
+            options.jobs =
+                value.parse::<usize>().ok()
+                    .filter(|jobs| *jobs > 0)
+                    .ok_or(format!("invalid number of jobs: '{}'", value))?;
+        } else if arg == "--parallel" {
+            options.jobs = pool::available_jobs();
+        } else if arg == "--no-cache" {
+            options.cache = false;
+        } else if arg == "--clear-cache" {
+            options.clear_cache = true;
+        } else if arg == "--format" {
+            options.format =
+                match args.next().as_deref() {
+                    Some("table") => Format::Table,
+                    Some("json") => Format::Json,
+                    Some(format) => return Err(format!("unknown format: '{}'", format)),
+                    None => return Err("missing value for --format".to_string())
+                };
+        } else if arg == "--runs" {
+            let value = args.next().ok_or("missing value for --runs")?;
+            runs = Some(
+                value.parse::<usize>().ok()
+                    .filter(|runs| *runs > 0)
+                    .ok_or(format!("invalid number of runs: '{}'", value))?);
+        } else if arg == "--csv" {
+            csv = Some(args.next().ok_or("missing value for --csv")?);
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option: {}", arg));
+        } else {
+            options.days.append(&mut parse_day_selection(&arg)?);
+        }
+    }
+
+    if options.days.is_empty() && !options.clear_cache {
+        return Err("no days selected".to_string());
+    }
+
+    if options.input.is_some() && options.days.len() > 1 {
+        return Err("an explicit input can only be used with a single day".to_string());
+    }
+
+    if bench {
+        options.bench = Some(BenchOptions { runs: runs.unwrap_or(bench::DEFAULT_RUNS), csv });
+    } else if runs.is_some() || csv.is_some() {
+        return Err("--runs and --csv require --bench".to_string());
+    }
+
+    if options.verbosity > 0 && options.bench.is_some() {
+        return Err("--verbose cannot be combined with --bench, tracing distorts the timings".to_string());
+    }
+
+    if options.jobs > 1 && options.bench.is_some() {
+        return Err("--jobs cannot be combined with --bench, concurrent runs distort the timings".to_string());
+    }
+
+    if options.check && options.bench.is_some() {
+        return Err("--check cannot be combined with --bench".to_string());
+    }
+
+    if options.format == Format::Json && (options.check || options.bench.is_some()) {
+        return Err("--format json cannot be combined with --check or --bench".to_string());
+    }
+
+    if options.check && options.input.is_some() {
+        return Err("--check compares the answers of the default inputs".to_string());
+    }
+
+    // checking and benchmarking are about the solvers, tracing needs them to run
+    options.cache &= !options.check && options.bench.is_none() && options.verbosity == 0;
+
+    Ok(options)
+}
+
+// parses the arguments of "aoc generate": <day> [--seed <n>] [--size <n>]
+pub fn parse_generate_options(args: impl IntoIterator<Item = String>) -> Result<GenerateOptions, String> {
+    let mut day = None;
+    let mut seed = DEFAULT_SEED;
+    let mut size = DEFAULT_SIZE;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            let value = args.next().ok_or("missing value for --seed")?;
+            seed = value.parse().map_err(|_| format!("invalid seed: '{}'", value))?;
+        } else if arg == "--size" {
+            let value = args.next().ok_or("missing value for --size")?;
+            size = value.parse().map_err(|_| format!("invalid size: '{}'", value))?;
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option: {}", arg));
+        } else if day.is_none() {
+            let number = arg.parse::<u8>().map_err(|_| format!("invalid day: '{}'", arg))?;
+            day = Some(find_day(number).ok_or(format!("no solution for day {}", number))?.number);
+        } else {
+            return Err(format!("unexpected argument: {}", arg));
+        }
+    }
+
+    Ok(GenerateOptions { day: day.ok_or("no day selected")?, seed, size })
+}
+
+// parses the arguments of the anonymize command: <day> [--input <path>|- | --text <text>] [--seed <n>] [<output>]
+pub fn parse_anonymize_options(args: impl IntoIterator<Item = String>) -> Result<AnonymizeOptions, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut seed = DEFAULT_SEED;
+    let mut output = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if let Some(parsed_input) = parse_input_option(&arg, &mut args)? {
+            input = Some(parsed_input);
+        } else if arg == "--seed" {
+            let value = args.next().ok_or("missing value for --seed")?;
+            seed = value.parse().map_err(|_| format!("invalid seed: '{}'", value))?;
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option: {}", arg));
+        } else if day.is_none() {
+            let number = arg.parse::<u8>().map_err(|_| format!("invalid day: '{}'", arg))?;
+            day = Some(find_day(number).ok_or(format!("no solution for day {}", number))?.number);
+        } else if output.is_none() {
+            output = Some(arg);
+        } else {
+            return Err(format!("unexpected argument: {}", arg));
+        }
+    }
+
+    Ok(AnonymizeOptions { day: day.ok_or("no day selected")?, input, seed, output })
+}
+
+// parses the arguments of the render command: <day> [--input <path>|- | --text <text>] [--scale <n>] <output.ppm|.svg>
+pub fn parse_render_options(args: impl IntoIterator<Item = String>) -> Result<RenderOptions, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut scale = render::DEFAULT_SCALE;
+    let mut output = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if let Some(parsed_input) = parse_input_option(&arg, &mut args)? {
+            input = Some(parsed_input);
+        } else if arg == "--scale" {
+            let value = args.next().ok_or("missing value for --scale")?;
+            scale = value.parse().ok().filter(|scale| *scale > 0).ok_or(format!("invalid scale: '{}'", value))?;
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option: {}", arg));
+        } else if day.is_none() {
+            let number = arg.parse::<u8>().map_err(|_| format!("invalid day: '{}'", arg))?;
+            let found = find_day(number).ok_or(format!("no solution for day {}", number))?;
+            if !found.can_render() {
+                return Err(format!("day {} has no image", number));
+            }
+            day = Some(found.number);
+        } else if output.is_none() {
+            output = Some(arg);
+        } else {
+            return Err(format!("unexpected argument: {}", arg));
+        }
+    }
+
+    let day = day.ok_or("no day selected")?;
+    let output = output.ok_or("no output file")?;
+    let format = ImageFormat::from_path(&output).ok_or(format!("unknown image format: '{}', use .ppm or .svg", output))?;
+
+    Ok(RenderOptions { day, input, scale, output, format })
+}
+
+// parses the arguments of the shell binary: <day> [--input <path> | --text <text>]
+//
+// stdin is not available as input, the shell reads its commands from there
+pub fn parse_shell_options(args: impl IntoIterator<Item = String>) -> Result<ShellOptions, String> {
+    let mut day = None;
+    let mut input = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if let Some(parsed_input) = parse_input_option(&arg, &mut args)? {
+            if parsed_input == Input::Stdin {
+                return Err("stdin is reserved for the commands".to_string());
+            }
+            input = Some(parsed_input);
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option: {}", arg));
+        } else if day.is_none() {
+            let number = arg.parse::<u8>().map_err(|_| format!("invalid day: '{}'", arg))?;
+            let found = find_day(number).ok_or(format!("no solution for day {}", number))?;
+            if !found.has_shell() {
+                return Err(format!("day {} has no shell commands", number));
+            }
+            day = Some(found.number);
+        } else {
+            return Err(format!("unexpected argument: {}", arg));
+        }
+    }
+
+    Ok(ShellOptions { day: day.ok_or("no day selected")?, input })
+}
+
+// parses the arguments of a dayNN binary: [<path>|- | --text <text>]
+pub fn parse_day_options(args: impl IntoIterator<Item = String>) -> Result<Option<Input>, String> {
+    let mut input = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        if let Some(parsed_input) = parse_input_option(&arg, &mut args)? {
+            input = Some(parsed_input);
+        } else if arg.starts_with("--") {
+            return Err(format!("unknown option: {}", arg));
+        } else if input.is_none() {
+            input = Some(Input::from_arg(&arg));
+        } else {
+            return Err(format!("unexpected argument: {}", arg));
+        }
+    }
+
+    Ok(input)
+}
+
+// entry point shared by the dayNN binaries
+pub fn main_for_day(number: u8) -> io::Result<()> {
+    let day = find_day(number).expect("day is registered in DAYS");
+    let input =
+        parse_day_options(std::env::args().skip(1))
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, format!("{}\nusage: day{:02} [<input>|-] [--text <text>]", err, number)))?
+            .unwrap_or(day.default_input());
+
+    let answers = run_parallel(&[(day, input)], 1).remove(0).answers;
+
+    print!("{}", format_table(std::slice::from_ref(&answers)));
+
+    answers.part1.and(answers.part2).map(|_| ())
+}
+
+#[cfg(test)]
+mod test {
+    mod parse_day_selection {
+        use crate::runner::parse_day_selection;
+
+        #[test]
+        fn it_should_parse_a_single_day() {
+            assert_eq!(Ok(vec![7]), parse_day_selection("7"));
+        }
+
+        #[test]
+        fn it_should_parse_an_inclusive_range_of_days() {
+            assert_eq!(Ok(vec![1, 2, 3]), parse_day_selection("1..3"));
+            assert_eq!(Ok(vec![3, 4, 5]), parse_day_selection("3..=5"));
+            assert_eq!(Ok((1..=11).collect()), parse_day_selection("1..11"));
+        }
+
+        #[test]
+        fn it_should_select_all_days() {
+            assert_eq!(Ok((1..=11).collect()), parse_day_selection("all"));
+        }
+
+        #[test]
+        fn it_should_reject_unknown_days() {
+            assert!(parse_day_selection("0").is_err());
+            assert!(parse_day_selection("26").is_err());
+            assert!(parse_day_selection("5..3").is_err());
+            assert!(parse_day_selection("seven").is_err());
+        }
+    }
+
+    mod parse_options {
+        use crate::input_helper::Input;
+        use crate::runner::{parse_day_options, parse_options, BenchOptions, Format};
+
+        fn args(args: &[&str]) -> Vec<String> {
+            args.iter().map(|arg| arg.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_collect_all_selected_days() {
+            let options = parse_options(args(&["1..3", "7"])).unwrap();
+
+            assert_eq!(vec![1, 2, 3, 7], options.days);
+            assert_eq!(None, options.input);
+            assert_eq!(None, options.bench);
+            assert!(!options.check);
+            assert_eq!(Format::Table, options.format);
+        }
+
+        #[test]
+        fn it_should_parse_the_output_format() {
+            assert_eq!(Format::Json, parse_options(args(&["--format", "json", "all"])).unwrap().format);
+            assert_eq!(Format::Table, parse_options(args(&["5", "--format", "table"])).unwrap().format);
+            assert!(parse_options(args(&["5", "--format", "xml"])).is_err(), "unknown format");
+            assert!(parse_options(args(&["5", "--format", "json", "--check"])).is_err(), "json check report");
+        }
+
+        #[test]
+        fn it_should_count_the_verbosity() {
+            assert_eq!(0, parse_options(args(&["5"])).unwrap().verbosity);
+            assert_eq!(1, parse_options(args(&["-v", "5"])).unwrap().verbosity);
+            assert_eq!(2, parse_options(args(&["5", "-vv"])).unwrap().verbosity);
+            assert_eq!(2, parse_options(args(&["--verbose", "-v", "5"])).unwrap().verbosity);
+            assert!(parse_options(args(&["-v", "--bench", "5"])).is_err(), "tracing benchmarks");
+            assert!(parse_options(args(&["-vx", "5"])).is_err(), "unknown flag");
+        }
+
+        #[test]
+        fn it_should_parse_the_check_mode() {
+            assert!(parse_options(args(&["--check", "all"])).unwrap().check);
+            assert!(parse_options(args(&["--check", "--bench", "5"])).is_err(), "check and benchmark");
+            assert!(parse_options(args(&["--check", "5", "--input", "example"])).is_err(), "check with explicit input");
+        }
+
+        #[test]
+        fn it_should_parse_the_benchmark_options() {
+            assert_eq!(
+                Some(BenchOptions { runs: 10, csv: None }),
+                parse_options(args(&["all", "--bench"])).unwrap().bench
+            );
+
+            assert_eq!(
+                Some(BenchOptions { runs: 3, csv: Some("timings.csv".to_string()) }),
+                parse_options(args(&["--bench", "--runs", "3", "--csv", "timings.csv", "5"])).unwrap().bench
+            );
+
+            assert!(parse_options(args(&["5", "--runs", "3"])).is_err(), "runs without benchmark");
+            assert!(parse_options(args(&["5", "--bench", "--runs", "0"])).is_err(), "no runs");
+            assert!(parse_options(args(&["5", "--bench", "--csv"])).is_err(), "missing csv path");
+        }
+
+        #[test]
+        fn it_should_accept_an_explicit_input_for_a_single_day() {
+            assert_eq!(
+                Some(Input::File("example".to_string())),
+                parse_options(args(&["5", "--input", "example"])).unwrap().input
+            );
+
+            assert_eq!(
+                Some(Input::Stdin),
+                parse_options(args(&["--input", "-", "5"])).unwrap().input
+            );
+
+            assert_eq!(
+                Some(Input::Text("0 3 6\n1 3 6".to_string())),
+                parse_options(args(&["9", "--text", "0 3 6\\n1 3 6"])).unwrap().input
+            );
+        }
+
+        #[test]
+        fn it_should_parse_the_number_of_jobs() {
+            assert_eq!(1, parse_options(args(&["all"])).unwrap().jobs);
+            assert_eq!(4, parse_options(args(&["--jobs", "4", "all"])).unwrap().jobs);
+            assert_eq!(2, parse_options(args(&["all", "-j", "2"])).unwrap().jobs);
+            assert!(parse_options(args(&["--parallel", "all"])).unwrap().jobs >= 1);
+            assert!(parse_options(args(&["--jobs", "0", "all"])).is_err(), "no threads");
+            assert!(parse_options(args(&["--jobs", "2", "--bench", "all"])).is_err(), "parallel benchmark");
+        }
+
+        #[test]
+        fn it_should_parse_the_cache_flags() {
+            assert!(parse_options(args(&["5"])).unwrap().cache);
+            assert!(!parse_options(args(&["--no-cache", "5"])).unwrap().cache);
+            assert!(!parse_options(args(&["--check", "5"])).unwrap().cache);
+            assert!(!parse_options(args(&["--bench", "5"])).unwrap().cache);
+            assert!(!parse_options(args(&["-v", "5"])).unwrap().cache);
+
+            let options = parse_options(args(&["--clear-cache"])).unwrap();
+            assert!(options.clear_cache);
+            assert!(options.days.is_empty());
+        }
+
+        #[test]
+        fn it_should_reject_invalid_arguments() {
+            assert!(parse_options(args(&[])).is_err(), "no days");
+            assert!(parse_options(args(&["1..2", "--input", "example"])).is_err(), "input for multiple days");
+            assert!(parse_options(args(&["1", "--input"])).is_err(), "missing input value");
+            assert!(parse_options(args(&["1", "--unknown"])).is_err(), "unknown option");
+        }
+
+        #[test]
+        fn it_should_parse_the_input_of_a_day_binary() {
+            assert_eq!(Ok(None), parse_day_options(args(&[])));
+            assert_eq!(Ok(Some(Input::Stdin)), parse_day_options(args(&["-"])));
+            assert_eq!(Ok(Some(Input::File("example".to_string()))), parse_day_options(args(&["example"])));
+            assert_eq!(Ok(Some(Input::Text("RL".to_string()))), parse_day_options(args(&["--text", "RL"])));
+            assert!(parse_day_options(args(&["example", "other"])).is_err());
+        }
+    }
+
+    mod parse_generate_options {
+        use crate::runner::{parse_generate_options, GenerateOptions};
+
+        fn args(args: &[&str]) -> Vec<String> {
+            args.iter().map(|arg| arg.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_use_default_seed_and_size() {
+            assert_eq!(Ok(GenerateOptions { day: 8, seed: 1, size: 100 }), parse_generate_options(args(&["8"])));
+        }
+
+        #[test]
+        fn it_should_parse_seed_and_size() {
+            assert_eq!(
+                Ok(GenerateOptions { day: 10, seed: 42, size: 5000 }),
+                parse_generate_options(args(&["--size", "5000", "10", "--seed", "42"]))
+            );
+        }
+
+        #[test]
+        fn it_should_reject_invalid_arguments() {
+            assert!(parse_generate_options(args(&[])).is_err(), "no day");
+            assert!(parse_generate_options(args(&["26"])).is_err(), "unknown day");
+            assert!(parse_generate_options(args(&["1..3"])).is_err(), "multiple days");
+            assert!(parse_generate_options(args(&["5", "--seed", "-1"])).is_err(), "invalid seed");
+            assert!(parse_generate_options(args(&["5", "--size"])).is_err(), "missing size");
+        }
+    }
+
+    mod parse_anonymize_options {
+        use crate::input_helper::Input;
+        use crate::runner::{parse_anonymize_options, AnonymizeOptions};
+
+        fn args(args: &[&str]) -> Vec<String> {
+            args.iter().map(|arg| arg.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_parse_day_input_seed_and_output() {
+            assert_eq!(Ok(AnonymizeOptions { day: 8, input: None, seed: 1, output: None }), parse_anonymize_options(args(&["8"])));
+            assert_eq!(
+                Ok(AnonymizeOptions { day: 7, input: Some(Input::Stdin), seed: 42, output: Some("tests/fixtures/day07/input.txt".to_string()) }),
+
+                parse_anonymize_options(args(&["--seed", "42", "7", "--input", "-", "tests/fixtures/day07/input.txt"]))
+            );
+        }
+
+        #[test]
+        fn it_should_reject_invalid_arguments() {
+            assert!(parse_anonymize_options(args(&[])).is_err(), "no day");
+            assert!(parse_anonymize_options(args(&["12"])).is_err(), "unknown day");
+            assert!(parse_anonymize_options(args(&["7", "--seed", "x"])).is_err(), "invalid seed");
+            assert!(parse_anonymize_options(args(&["7", "a.txt", "b.txt"])).is_err(), "two outputs");
+        }
+    }
+
+    mod parse_render_options {
+        use crate::input_helper::Input;
+        use crate::render::ImageFormat;
+        use crate::runner::{parse_render_options, RenderOptions};
+
+        fn args(args: &[&str]) -> Vec<String> {
+            args.iter().map(|arg| arg.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_use_the_default_input_and_scale() {
+            assert_eq!(
+                Ok(RenderOptions { day: 10, input: None, scale: 4, output: "loop.svg".to_string(), format: ImageFormat::Svg }),
+                parse_render_options(args(&["10", "loop.svg"]))
+            );
+        }
+
+        #[test]
+        fn it_should_parse_input_and_scale() {
+            assert_eq!(
+                Ok(RenderOptions { day: 3, input: Some(Input::File("example".to_string())), scale: 10, output: "out/day03.ppm".to_string(), format: ImageFormat::Ppm }),
+                parse_render_options(args(&["--scale", "10", "3", "--input", "example", "out/day03.ppm"]))
+            );
+        }
+
+        #[test]
+        fn it_should_reject_invalid_arguments() {
+            assert!(parse_render_options(args(&["11"])).is_err(), "no output");
+            assert!(parse_render_options(args(&["day11.svg"])).is_err(), "no day");
+            assert!(parse_render_options(args(&["7", "day07.svg"])).is_err(), "day without image");
+            assert!(parse_render_options(args(&["11", "day11.png"])).is_err(), "unknown format");
+            assert!(parse_render_options(args(&["11", "--scale", "0", "day11.svg"])).is_err(), "invalid scale");
+        }
+    }
+
+    mod parse_shell_options {
+        use crate::input_helper::Input;
+        use crate::runner::{parse_shell_options, ShellOptions};
+
+        fn args(args: &[&str]) -> Vec<String> {
+            args.iter().map(|arg| arg.to_string()).collect()
+        }
+
+        #[test]
+        fn it_should_parse_the_day_and_input() {
+            assert_eq!(Ok(ShellOptions { day: 5, input: None }), parse_shell_options(args(&["5"])));
+            assert_eq!(
+                Ok(ShellOptions { day: 8, input: Some(Input::File("example".to_string())) }),
+                parse_shell_options(args(&["--input", "example", "8"]))
+            );
+        }
+
+        #[test]
+        fn it_should_reject_invalid_arguments() {
+            assert!(parse_shell_options(args(&[])).is_err(), "no day");
+            assert!(parse_shell_options(args(&["9"])).is_err(), "day without commands");
+            assert!(parse_shell_options(args(&["7", "--input", "-"])).is_err(), "stdin");
+            assert!(parse_shell_options(args(&["7", "11"])).is_err(), "multiple days");
+        }
+    }
+
+    mod format_table {
+        use std::io;
+
+        use crate::runner::{format_table, Answers};
+
+        #[test]
+        fn it_should_align_the_answers_in_columns() {
+            assert_eq!(
+"Day | Part 1           | Part 2
+----+------------------+--------
+  7 | 42               | 1234567
+ 11 | error: no digits | 3
+",
+
+                format_table(&[
+                    Answers { day: 7, part1: Ok("42".to_string()), part2: Ok("1234567".to_string()) },
+                    Answers { day: 11, part1: Err(io::Error::other("no digits")), part2: Ok("3".to_string()) }
+                ])
+            );
+        }
+    }
+
+    mod format_json {
+        use std::io;
+        use std::time::Duration;
+
+        use crate::input_helper::Input;
+        use crate::runner::{find_day, format_json, Answers, Run};
+
+        #[test]
+        fn it_should_write_an_object_per_day() {
+            let runs = [
+                Run {
+                    answers: Answers { day: 9, part1: Ok("114".to_string()), part2: Err(io::Error::other("no \"sequence\"")) },
+                    input: Input::File("example".to_string()),
+                    input_hash: Some(0xcbf29ce484222325),
+                    durations: [Some(Duration::from_nanos(1500)), Some(Duration::from_nanos(20)), None],
+                    warnings: vec!["removed the byte order mark".to_string()],
+                    cached: false
+                },
+                Run {
+                    answers: Answers { day: 10, part1: Err(io::Error::other("not found")), part2: Err(io::Error::other("not found")) },
+                    input: Input::Stdin,
+                    input_hash: None,
+                    durations: [None; 3],
+                    warnings: vec![],
+                    cached: true
+                }
+            ];
+
+            assert_eq!(
+r#"[
+{"day":9,"input":"example","input_hash":"cbf29ce484222325","part1":{"answer":"114"},"part2":{"error":"no \"sequence\""},"timings_ns":{"parse":1500,"part1":20,"part2":null},"warnings":["removed the byte order mark"],"cached":false},
+{"day":10,"input":"<stdin>","input_hash":null,"part1":{"error":"not found"},"part2":{"error":"not found"},"timings_ns":{"parse":null,"part1":null,"part2":null},"warnings":[],"cached":true}
+]
+"#,
+
+                format_json(&runs)
+            );
+        }
+
+        #[test]
+        fn it_should_time_a_successful_run() {
+            let run = find_day(9).unwrap().run_timed(&Input::from_text("0 3 6 9 12 15"));
+
+            assert_eq!("18", run.answers.part1.unwrap());
+            assert!(run.input_hash.is_some());
+            assert!(run.durations.iter().all(|duration| duration.is_some()));
+        }
+    }
+
+    mod run_cached {
+        use std::{env, fs};
+
+        use crate::cache::{Cache, Key};
+        use crate::input_helper::Input;
+        use crate::runner::{find_day, run_cached, Day, DAYS};
+
+        #[test]
+        fn it_should_reuse_the_stored_answers() {
+            let dir = env::temp_dir().join(format!("aoc-run-cached-{}", std::process::id()));
+            let cache = Cache::new(&dir);
+            let day09 = find_day(9).unwrap();
+            let days = [(day09, Input::from_text("0 3 6 9 12 15 ")), (find_day(6).unwrap(), Input::from_text("Time: 7\nDistance: 9"))];
+
+            let solved = run_cached(&days, 1, &cache);
+
+            assert!(solved.iter().all(|run| !run.cached));
+            assert_eq!("18", solved[0].answers.part1.as_ref().unwrap());
+            assert_eq!(Input::Text("0 3 6 9 12 15 ".to_string()), solved[0].input);
+            assert_eq!(vec!["trimmed trailing whitespace from 1 lines".to_string()], solved[0].warnings);
+
+            // a changed answer in the cache shows that it is read instead of solving again
+            let key = Key { day: 9, part: 1, input_hash: solved[0].input_hash.unwrap(), version: &day09.solver_version() };
+            cache.store(&key, "cached").unwrap();
+
+            let loaded = run_cached(&days, 2, &cache);
+
+            assert!(loaded.iter().all(|run| run.cached));
+            assert_eq!("cached", loaded[0].answers.part1.as_ref().unwrap());
+            assert_eq!(solved[1].answers.part2.as_ref().unwrap(), loaded[1].answers.part2.as_ref().unwrap());
+            assert_eq!([None; 3], loaded[0].durations);
+            assert_eq!(solved[0].warnings, loaded[0].warnings);
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn it_should_isolate_a_panicking_day_with_a_single_job() {
+            let dir = env::temp_dir().join(format!("aoc-run-cached-panicking-{}", std::process::id()));
+            let cache = Cache::new(&dir);
+            let panicking = Day { parse: |_| panic!("no solution for this day"), ..DAYS[8] };
+            let days = [(&panicking, Input::from_text("0 3 6")), (find_day(9).unwrap(), Input::from_text("0 3 6 9 12 15"))];
+
+            let runs = run_cached(&days, 1, &cache);
+
+            assert_eq!("panicked: no solution for this day", runs[0].answers.part1.as_ref().unwrap_err().to_string());
+            assert_eq!("18", runs[1].answers.part1.as_ref().unwrap());
+
+            fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn it_should_not_store_failed_answers() {
+            let dir = env::temp_dir().join(format!("aoc-run-cached-failed-{}", std::process::id()));
+            let cache = Cache::new(&dir);
+            let days = [(find_day(7).unwrap(), Input::from_text("not a hand"))];
+
+            assert!(run_cached(&days, 1, &cache)[0].answers.part1.is_err());
+            assert!(!run_cached(&days, 1, &cache)[0].cached);
+            assert_eq!(0, cache.clear().unwrap());
+        }
+    }
+
+    mod run_parallel {
+        use crate::input_helper::Input;
+        use crate::runner::{find_day, run_parallel, Day, DAYS};
+        use crate::trace;
+
+        #[test]
+        fn it_should_give_the_same_answers_as_a_sequential_run() {
+            let inputs: Vec<(&Day, Input)> =
+                DAYS.iter()
+                    .map(|day| (day, Input::Text(day.generate(3, 20).join("\n"))))
+                    .collect();
+
+            let runs = run_parallel(&inputs, 4);
+
+            assert_eq!(inputs.len(), runs.len());
+            for ((day, input), run) in inputs.iter().zip(runs.iter()) {
+                let expected = day.run_timed(input);
+
+                assert_eq!(day.number, run.answers.day);
+                assert_eq!(expected.answers.part1.unwrap(), *run.answers.part1.as_ref().unwrap(), "day {} part 1", day.number);
+                assert_eq!(expected.answers.part2.unwrap(), *run.answers.part2.as_ref().unwrap(), "day {} part 2", day.number);
+                assert_eq!(expected.input_hash, run.input_hash);
+                assert!(run.durations.iter().all(|duration| duration.is_some()));
+            }
+        }
+
+        #[test]
+        fn it_should_fail_only_the_days_with_invalid_input() {
+            let inputs = [
+                (find_day(9).unwrap(), Input::from_text("0 3 6 9 12 15")),
+                (find_day(9).unwrap(), Input::from_text("0 three 6")),
+                (find_day(1).unwrap(), Input::File("input/does-not-exist".to_string())),
+                (find_day(1).unwrap(), Input::from_text("a1b2c"))
+            ];
+
+            let runs = run_parallel(&inputs, 2);
+
+            assert_eq!("18", runs[0].answers.part1.as_ref().unwrap());
+            assert!(runs[1].answers.part1.is_err() && runs[1].answers.part2.is_err());
+            assert!(runs[1].input_hash.is_some(), "the input was read");
+            assert!(runs[2].answers.part1.is_err() && runs[2].input_hash.is_none());
+            assert_eq!("12", runs[3].answers.part1.as_ref().unwrap());
+        }
+
+        #[test]
+        fn it_should_isolate_a_panicking_day_with_a_single_job() {
+            let panicking = Day { parse: |_| panic!("no solution for this day"), ..DAYS[8] };
+            let inputs = [
+                (&panicking, Input::from_text("0 3 6 9 12 15")),
+                (find_day(9).unwrap(), Input::from_text("0 3 6 9 12 15"))
+            ];
+
+            let runs = run_parallel(&inputs, 1);
+
+            assert_eq!("panicked: no solution for this day", runs[0].answers.part1.as_ref().unwrap_err().to_string());
+            assert!(runs[0].answers.part2.is_err());
+            assert_eq!("18", runs[1].answers.part1.as_ref().unwrap());
+        }
+
+        #[test]
+        fn it_should_emit_the_traces_in_the_order_of_the_days() {
+            let inputs = [
+                (find_day(9).unwrap(), Input::from_text("0 3 6")),
+                (find_day(1).unwrap(), Input::from_text("a1b2c"))
+            ];
+
+            let (_, lines) = trace::capture(1, || run_parallel(&inputs, 4));
+            let announcements: Vec<&String> = lines.iter().filter(|line| line.starts_with("day ")).collect();
+
+            assert_eq!(vec!["day 9 part 1:", "day 9 part 2:", "day 1 part 1:", "day 1 part 2:"], announcements);
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::Solution;
+
+// Queries on the parsed model of a day, issued line by line in an interactive shell
+
+// command of a day: "<name> <arguments>"
+pub struct Command<S> {
+    pub name: &'static str,
+    pub arguments: &'static str,
+    pub help: &'static str,
+    pub run: fn(&S, &[&str]) -> Result<String, String>
+}
+
+// days with a command table
+pub trait Commands: Solution + Sized + 'static {
+    const COMMANDS: &'static [Command<Self>];
+}
+
+pub trait Shell {
+    // output of a single line of input, the message of a failing command otherwise
+    fn execute(&self, line: &str) -> Result<String, String>;
+
+    fn help(&self) -> String;
+}
+
+impl<S: Commands> Shell for S {
+    fn execute(&self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        match words.as_slice() {
+            [] => Ok(String::new()),
+            ["help"] => Ok(self.help()),
+            ["part1"] => self.part1().map_err(|err| err.to_string()),
+            ["part2"] => self.part2().map_err(|err| err.to_string()),
+            [name, arguments @ ..] => {
+                let command =
+                    S::COMMANDS.iter()
+                        .find(|command| command.name == *name)
+                        .ok_or(format!("unknown command: {}, try help", name))?;
+
+                (command.run)(self, arguments)
+                    .map_err(|err| format!("{}\nusage: {} {}", err, command.name, command.arguments))
+            }
+        }
+    }
+
+    fn help(&self) -> String {
+        let mut rows: Vec<(String, &str)> =
+            S::COMMANDS.iter()
+                .map(|command| (format!("{} {}", command.name, command.arguments), command.help))
+                .collect();
+        rows.extend([
+            ("part1".to_string(), "answer of part 1"),
+            ("part2".to_string(), "answer of part 2"),
+            ("help".to_string(), "this list"),
+            ("quit".to_string(), "leave the shell")
+        ]);
+
+        let width = rows.iter().map(|(usage, _)| usage.chars().count()).max().unwrap_or(0);
+
+        rows.iter()
+            .map(|(usage, help)| format!("{:<w$}  {}", usage, help, w = width))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+// the exact number of arguments a command takes
+pub fn arguments<'a, const N: usize>(arguments: &[&'a str]) -> Result<[&'a str; N], String> {
+    arguments.try_into()
+        .map_err(|_| format!("expected {} arguments but found {}", N, arguments.len()))
+}
+
+pub fn parse_argument<T: FromStr>(argument: &str, what: &str) -> Result<T, String> {
+    argument.parse()
+        .map_err(|_| format!("invalid {}: '{}'", what, argument))
+}
+
+// reads commands until "quit", "exit" or the end of the input, prompting for each one
+pub fn repl(shell: &dyn Shell, input: impl BufRead, mut output: impl Write, prompt: &str) -> io::Result<()> {
+    write!(output, "{}", prompt)?;
+    output.flush()?;
+
+    for line in input.lines() {
+        let line = line?;
+
+        match line.trim() {
+            "quit" | "exit" => return Ok(()),
+            line =>
+                match shell.execute(line) {
+                    Ok(text) if text.is_empty() => {}
+                    Ok(text) => writeln!(output, "{}", text)?,
+                    Err(err) => writeln!(output, "error: {}", err)?
+                }
+        }
+
+        write!(output, "{}", prompt)?;
+        output.flush()?;
+    }
+
+    writeln!(output)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io;
+
+    use crate::Solution;
+    use crate::parse_error::ParseError;
+    use crate::shell::{arguments, parse_argument, Command, Commands};
+
+    // numbers, one per line, with commands to query them
+    pub struct Numbers {
+        pub values: Vec<i64>
+    }
+
+    impl Solution for Numbers {
+        fn parse(lines: &[String]) -> Result<Self, ParseError> {
+            Ok(Numbers { values: lines.iter().map(|line| line.parse().unwrap()).collect() })
+        }
+
+        fn part1(&self) -> io::Result<String> {
+            Ok(self.values.iter().sum::<i64>().to_string())
+        }
+
+        fn part2(&self) -> io::Result<String> {
+            Err(io::Error::other("not solved"))
+        }
+    }
+
+    impl Commands for Numbers {
+        const COMMANDS: &'static [Command<Numbers>] = &[
+            Command {
+                name: "get",
+                arguments: "<index>",
+                help: "number at the index",
+                run: |numbers, args| {
+                    let [index] = arguments(args)?;
+                    let index: usize = parse_argument(index, "index")?;
+
+                    numbers.values.get(index).map(|value| value.to_string()).ok_or(format!("no number at {}", index))
+                }
+            }
+        ];
+    }
+
+    fn numbers() -> Numbers {
+        Numbers::parse(&["3".to_string(), "-1".to_string(), "40".to_string()]).unwrap()
+    }
+
+    mod execute {
+        use crate::shell::Shell;
+        use crate::shell::test::numbers;
+
+        #[test]
+        fn it_should_run_the_commands_of_the_day() {
+            assert_eq!(Ok("40".to_string()), numbers().execute("get 2"));
+            assert_eq!(Ok("-1".to_string()), numbers().execute("  get   1 "));
+            assert_eq!(Ok("42".to_string()), numbers().execute("part1"));
+            assert_eq!(Ok(String::new()), numbers().execute(""));
+        }
+
+        #[test]
+        fn it_should_report_failing_commands_with_their_usage() {
+            assert_eq!(Err("no number at 7\nusage: get <index>".to_string()), numbers().execute("get 7"));
+            assert_eq!(Err("invalid index: 'x'\nusage: get <index>".to_string()), numbers().execute("get x"));
+            assert_eq!(Err("expected 1 arguments but found 2\nusage: get <index>".to_string()), numbers().execute("get 1 2"));
+            assert_eq!(Err("unknown command: put, try help".to_string()), numbers().execute("put 1"));
+            assert_eq!(Err("not solved".to_string()), numbers().execute("part2"));
+        }
+
+        #[test]
+        fn it_should_list_the_commands() {
+            assert_eq!(
+"get <index>  number at the index
+part1        answer of part 1
+part2        answer of part 2
+help         this list
+quit         leave the shell",
+
+                numbers().help()
+            );
+        }
+    }
+
+    mod repl {
+        use crate::shell::repl;
+        use crate::shell::test::numbers;
+
+        #[test]
+        fn it_should_answer_each_line_until_quit() {
+            let mut output = vec![];
+
+            repl(&numbers(), "get 0\n\nget 9\nquit\nget 1\n".as_bytes(), &mut output, "> ").unwrap();
+
+            assert_eq!("> 3\n> > error: no number at 9\nusage: get <index>\n> ", String::from_utf8(output).unwrap());
+        }
+
+        #[test]
+        fn it_should_stop_at_the_end_of_the_input() {
+            let mut output = vec![];
+
+            repl(&numbers(), "part1".as_bytes(), &mut output, "").unwrap();
+
+            assert_eq!("42\n\n", String::from_utf8(output).unwrap());
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Narration of the reasoning of the solvers, printed to stderr
+//
+// verbosity 1 explains the decision for each item of the input, 2 adds the intermediate steps
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+thread_local! {
+    // verbosity and collected lines while capturing on this thread
+    static CAPTURE: RefCell<Option<(u8, Vec<String>)>> = const { RefCell::new(None) };
+}
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    CAPTURE.with_borrow(|capture| capture.as_ref().map(|(level, _)| *level))
+        .unwrap_or_else(|| VERBOSITY.load(Ordering::Relaxed))
+}
+
+pub fn enabled(level: u8) -> bool {
+    level <= verbosity()
+}
+
+pub fn emit(message: String) {
+    let message =
+        CAPTURE.with_borrow_mut(|capture|
+            match capture {
+                Some((_, lines)) => { lines.push(message); None }
+                None => Some(message)
+            });
+
+    if let Some(message) = message {
+        eprintln!("{}", message);
+    }
+}
+
+// runs f with the given verbosity on this thread and returns the lines traced meanwhile
+pub fn capture<R>(level: u8, f: impl FnOnce() -> R) -> (R, Vec<String>) {
+    let previous = CAPTURE.replace(Some((level, vec![])));
+    let result = f();
+    let (_, lines) = CAPTURE.replace(previous).unwrap();
+
+    (result, lines)
+}
+
+// trace!(level, "format", args...) emits the message if the verbosity is at least level
+#[macro_export]
+macro_rules! trace {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit(format!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    mod capture {
+        use crate::trace::{self, capture};
+
+        #[test]
+        fn it_should_collect_the_lines_up_to_the_verbosity() {
+            let (answer, lines) = capture(1, || {
+                crate::trace!(1, "step {}", 1);
+                crate::trace!(2, "detail of step {}", 1);
+
+                42
+            });
+
+            assert_eq!(42, answer);
+            assert_eq!(vec!["step 1"], lines);
+        }
+
+        #[test]
+        fn it_should_restore_the_previous_verbosity() {
+            let (_, lines) = capture(2, || {
+                let (_, inner_lines) = capture(0, || crate::trace!(1, "hidden"));
+                assert!(inner_lines.is_empty());
+
+                crate::trace!(2, "shown");
+            });
+
+            assert_eq!(vec!["shown"], lines);
+            assert!(!trace::enabled(1), "tracing is off by default");
+        }
+    }
+}
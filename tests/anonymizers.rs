@@ -0,0 +1,75 @@
+use std::fs;
+use std::path::Path;
+
+use adventofcode_2023::answers::KnownAnswers;
+use adventofcode_2023::runner::DAYS;
+
+fn lines(text: &str) -> Vec<String> {
+    text.lines().map(|line| line.to_string()).collect()
+}
+
+// the examples and a few generated inputs of each day, with the parts they can be solved for
+//
+// some examples only fit one of the parts, their other part has no published answer
+fn inputs(number: u8) -> Vec<(Vec<String>, Vec<u8>)> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("tests/fixtures/day{:02}", number));
+    let day = DAYS.iter().find(|day| day.number == number).unwrap();
+
+    let mut inputs: Vec<(Vec<String>, Vec<u8>)> =
+        fs::read_dir(directory).unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .map(|path| {
+                let answers = KnownAnswers::load(path.with_extension("answers").to_str().unwrap()).unwrap();
+                let parts = [(1, answers.part1), (2, answers.part2)].into_iter().filter(|(_, answer)| answer.is_some()).map(|(part, _)| part).collect();
+
+                (lines(&fs::read_to_string(path).unwrap()), parts)
+            })
+            .collect();
+    inputs.extend((0..3).map(|seed| (day.generate(seed, 20), vec![1, 2])));
+
+    inputs
+}
+
+#[test]
+fn it_should_anonymize_inputs_the_solutions_accept() {
+    let mut failures = vec![];
+
+    for day in DAYS.iter() {
+        for (input, parts) in inputs(day.number) {
+            for seed in 0..3 {
+                let anonymized = day.anonymize(seed, &input).unwrap();
+                let solution = day.parse(&anonymized).unwrap();
+
+                for part in parts.iter() {
+                    let answer = if *part == 1 { solution.part1() } else { solution.part2() };
+
+                    if let Err(err) = answer {
+                        failures.push(format!("day {} seed {} part {}: {}\n{}", day.number, seed, part, err, anonymized.join("\n")));
+                    }
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn it_should_anonymize_the_same_way_for_the_same_seed() {
+    for day in DAYS.iter() {
+        let input = day.generate(1, 30);
+
+        assert_eq!(day.anonymize(7, &input).unwrap(), day.anonymize(7, &input).unwrap(), "day {}", day.number);
+        assert_ne!(input, day.anonymize(7, &input).unwrap(), "day {}", day.number);
+    }
+}
+
+#[test]
+fn it_should_reject_inputs_the_solutions_reject() {
+    let input = lines("#?!\nno puzzle");
+
+    for day in DAYS.iter().filter(|day| day.parse(&input).is_err()) {
+        assert!(day.anonymize(1, &input).is_err(), "day {}", day.number);
+    }
+}
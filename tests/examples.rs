@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use adventofcode_2023::answers::KnownAnswers;
+use adventofcode_2023::input_helper::Input;
+use adventofcode_2023::runner::{Day, DAYS};
+
+// tests/fixtures/dayNN/<name>.txt holds a puzzle example, <name>.answers its published answers
+fn fixtures(day: &Day) -> Vec<(PathBuf, KnownAnswers)> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("tests/fixtures/day{:02}", day.number));
+
+    let mut inputs: Vec<PathBuf> =
+        fs::read_dir(&directory)
+            .unwrap_or_else(|err| panic!("{}: {}", directory.display(), err))
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .collect();
+    inputs.sort();
+
+    inputs.into_iter()
+        .map(|input| {
+            let answers_path = input.with_extension("answers");
+            let answers = KnownAnswers::load(answers_path.to_str().unwrap()).unwrap();
+
+            assert!(answers != KnownAnswers::default(), "{}: no answers", answers_path.display());
+
+            (input, answers)
+        })
+        .collect()
+}
+
+#[test]
+fn it_should_have_examples_for_every_day() {
+    for day in DAYS.iter() {
+        assert!(!fixtures(day).is_empty(), "no examples for day {}", day.number);
+    }
+}
+
+// failures of solving the example, parts without a published answer are not run
+fn solve_example(day: &Day, name: &str, lines: &[String], expected: &KnownAnswers) -> Vec<String> {
+    let mut failures = vec![];
+
+    let solution =
+        match day.parse(lines) {
+            Ok(solution) => solution,
+            Err(err) => return vec![format!("{}: {}", name, err)]
+        };
+
+    if let Some(expected) = &expected.part1 {
+        match solution.part1() {
+            Ok(answer) if answer == *expected => {}
+            answer => failures.push(format!("{} part 1: expected {} but got {:?}", name, expected, answer))
+        }
+    }
+
+    if let Some(expected) = &expected.part2 {
+        match solution.part2() {
+            Ok(answer) if answer == *expected => {}
+            answer => failures.push(format!("{} part 2: expected {} but got {:?}", name, expected, answer))
+        }
+    }
+
+    failures
+}
+
+#[test]
+fn it_should_solve_the_puzzle_examples() {
+    let mut failures = vec![];
+
+    for day in DAYS.iter() {
+        for (input, expected) in fixtures(day) {
+            let lines = Input::File(input.to_str().unwrap().to_string()).read_lines().unwrap();
+
+            failures.extend(solve_example(day, &input.display().to_string(), &lines, &expected));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn it_should_solve_the_puzzle_examples_saved_with_windows_line_endings() {
+    let mut failures = vec![];
+
+    for day in DAYS.iter() {
+        for (input, expected) in fixtures(day) {
+            // byte order mark, CRLF line endings, trailing spaces and an extra empty line at the end
+            let content: String =
+                fs::read_to_string(&input).unwrap()
+                    .lines()
+                    .map(|line| format!("{}  \r\n", line))
+                    .collect();
+            let lines = Input::Text(format!("\u{feff}{}\r\n", content)).read_lines().unwrap();
+
+            failures.extend(solve_example(day, &input.display().to_string(), &lines, &expected));
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,29 @@
+use adventofcode_2023::runner::DAYS;
+
+#[test]
+fn it_should_generate_inputs_the_solutions_accept() {
+    let mut failures = vec![];
+
+    for day in DAYS.iter() {
+        for seed in 0..5 {
+            let lines = day.generate(seed, 20);
+            let answers = day.solve(&lines);
+
+            for (part, answer) in [(1, &answers.part1), (2, &answers.part2)] {
+                if let Err(err) = answer {
+                    failures.push(format!("day {} seed {} part {}: {}\n{}", day.number, seed, part, err, lines.join("\n")));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+#[test]
+fn it_should_generate_the_same_input_for_the_same_seed() {
+    for day in DAYS.iter() {
+        assert_eq!(day.generate(7, 30), day.generate(7, 30), "day {}", day.number);
+        assert_ne!(day.generate(7, 30), day.generate(8, 30), "day {}", day.number);
+    }
+}
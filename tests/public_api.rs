@@ -0,0 +1,54 @@
+use adventofcode_2023::{day01, day05, day07, day11};
+use adventofcode_2023::Solution;
+use adventofcode_2023::grid::Grid;
+
+fn lines(input: &str) -> Vec<String> {
+    input.lines().map(|line| line.to_string()).collect()
+}
+
+#[test]
+fn it_should_reverse_location_ranges_to_seed_ranges() {
+    let (_, seed_to_location_map) = day05::process(&lines(include_str!("fixtures/day05/example.txt"))).unwrap();
+
+    let seed_ranges = seed_to_location_map.reverse(day05::Range { start: 46, length: 1 });
+
+    assert!(
+        seed_ranges.iter()
+            .flat_map(|range| range.start..range.start + range.length)
+            .any(|seed| seed == 82),
+        "seed 82 leads to location 46"
+    );
+    assert_eq!(46, seed_to_location_map.index(82));
+}
+
+#[test]
+fn it_should_rank_hands() {
+    let hand: day07::Hand = "KTJJT".parse().unwrap();
+
+    assert_eq!(day07::WinType::TwoPair, hand.get_win_type());
+    assert_eq!(day07::WinType::FourOfAKind, hand.get_win_type_part2());
+}
+
+#[test]
+fn it_should_measure_distances_in_the_expanded_universe() {
+    let universe = Grid::parse(&lines("#..\n...\n..#")).unwrap();
+
+    let rows_to_expand = day11::get_rows_to_expand(&universe);
+    let columns_to_expand = day11::get_columns_to_expand(&universe);
+
+    assert_eq!(vec![1], rows_to_expand);
+    assert_eq!(vec![1], columns_to_expand);
+    assert_eq!(6, day11::distance((0, 0), (2, 2), &rows_to_expand, &columns_to_expand, 2));
+}
+
+#[test]
+fn it_should_read_calibration_values_in_other_languages() {
+    let vocabulary = day01::DigitVocabulary::combine(&[
+        day01::DigitVocabulary::german(),
+        day01::DigitVocabulary::parse("# dutch\n1: een\n2: twee").unwrap()
+    ]).unwrap();
+
+    let day = day01::Day01::with_vocabulary(&lines("xdreizehn\ntweeacht\n4een"), vocabulary);
+
+    assert_eq!("102", day.part2().unwrap(), "33 + 28 + 41");
+}
@@ -0,0 +1,90 @@
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread;
+
+use adventofcode_2023::http;
+
+fn start_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || http::serve(listener));
+
+    address
+}
+
+// status code and body of the response to the raw request
+fn send(address: SocketAddr, request: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").expect("headers end with an empty line");
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    assert!(head.contains(&format!("Content-Length: {}", body.len())), "{}", head);
+
+    (status, body.to_string())
+}
+
+fn post(address: SocketAddr, path: &str, body: &str) -> (u16, String) {
+    send(address, &format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", path, body.len(), body))
+}
+
+#[test]
+fn it_should_list_the_available_days() {
+    let address = start_server();
+
+    assert_eq!(
+        (200, "{\"days\":[1,2,3,4,5,6,7,8,9,10,11]}\n".to_string()),
+
+        send(address, "GET /days HTTP/1.1\r\nHost: localhost\r\n\r\n")
+    );
+}
+
+#[test]
+fn it_should_answer_both_parts_of_a_posted_input() {
+    let address = start_server();
+
+    let (status, body) = post(address, "/day/6", include_str!("fixtures/day06/example.txt"));
+
+    assert_eq!(200, status);
+    assert!(body.starts_with("{\"day\":6,"), "{}", body);
+    assert!(body.contains("\"part1\":{\"answer\":\"288\""), "{}", body);
+    assert!(body.contains("\"part2\":{\"answer\":\"71503\""), "{}", body);
+}
+
+#[test]
+fn it_should_serve_concurrent_clients() {
+    let address = start_server();
+
+    let clients: Vec<_> = (0..4).map(|_| thread::spawn(move || post(address, "/day/9", "0 3 6 9 12 15\n").0)).collect();
+
+    for client in clients {
+        assert_eq!(200, client.join().unwrap());
+    }
+}
+
+#[test]
+fn it_should_report_errors_as_json() {
+    let address = start_server();
+
+    assert_eq!((404, "{\"error\":\"no solution for day 12\"}\n".to_string()), post(address, "/day/12", ""));
+    assert_eq!(405, send(address, "GET /day/1 HTTP/1.1\r\n\r\n").0);
+    assert_eq!(411, send(address, "POST /day/1 HTTP/1.1\r\n\r\n").0);
+    assert_eq!(400, send(address, "hello\r\n\r\n").0);
+}
+
+// the subtraction overflows in builds with overflow checks
+#[cfg(debug_assertions)]
+#[test]
+fn it_should_answer_a_panicking_day_with_an_internal_server_error() {
+    let address = start_server();
+
+    let (status, body) = post(address, "/day/9", "9223372036854775807 -9223372036854775807 9223372036854775807");
+
+    assert_eq!(500, status);
+    assert!(body.starts_with("{\"error\":\"day 9 panicked: attempt to subtract with overflow\"}"), "{}", body);
+    assert_eq!(200, post(address, "/day/9", "0 3 6 9 12 15\n").0, "the server keeps answering");
+}